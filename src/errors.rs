@@ -189,7 +189,7 @@ pub enum VB6ErrorKind {
     #[error("Thread Per Object is not a number.")]
     ThreadPerObjectUnparseable,
 
-    #[error("Unknown attribute in class header file. Must be one of: VB_Name, VB_GlobalNameSpace, VB_Creatable, VB_PredeclaredId, VB_Exposed, VB_Description, VB_Ext_KEY")]
+    #[error("Unknown attribute in class header file. Must be one of: VB_Name, VB_GlobalNameSpace, VB_Creatable, VB_PredeclaredId, VB_Exposed, VB_TemplateDerived, VB_Description, VB_Ext_KEY")]
     UnknownAttribute,
 
     #[error("Error parsing header")]
@@ -311,6 +311,151 @@ pub enum VB6ErrorKind {
 
     #[error("Internal Parser Error - please report this issue to the developers.")]
     InternalParseError,
+
+    #[error("None of the expected terminators were found.")]
+    TerminatorNotFound,
+
+    #[error("Control '{name}' is missing its closing 'End'")]
+    UnterminatedControl { name: String },
+
+    #[error("The object line is missing the version between the '#' separators")]
+    ObjectVersionMissing,
+
+    #[error("The object version can only contain hex digits and a '.'")]
+    ObjectVersionInvalid,
+
+    #[error("A {file_kind} file can not have a '{found}' as its root control")]
+    UnexpectedRootControl {
+        file_kind: &'static str,
+        found: String,
+    },
+}
+
+impl VB6ErrorKind {
+    /// Returns the stable error code for this kind of error.
+    ///
+    /// Each variant has its own code which never changes once assigned.
+    /// New variants must be given the next unused code rather than reusing
+    /// one, so that the codes can be relied on in documentation and when
+    /// searching for a specific error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::errors::VB6ErrorKind;
+    ///
+    /// assert_eq!(VB6ErrorKind::LineTypeUnknown.code(), "VB6E0005");
+    /// ```
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn code(&self) -> &'static str {
+        match self {
+            VB6ErrorKind::LikelyNonEnglishCharacterSet => "VB6E0001",
+            VB6ErrorKind::ReferenceExtraSections => "VB6E0002",
+            VB6ErrorKind::ReferenceMissingSections => "VB6E0003",
+            VB6ErrorKind::FirstLineNotProject => "VB6E0004",
+            VB6ErrorKind::LineTypeUnknown => "VB6E0005",
+            VB6ErrorKind::ProjectTypeUnknown => "VB6E0006",
+            VB6ErrorKind::NoLineEnding => "VB6E0007",
+            VB6ErrorKind::UnableToParseUuid => "VB6E0008",
+            VB6ErrorKind::PropertyNameUnparsable => "VB6E0009",
+            VB6ErrorKind::NoSemicolonSplit => "VB6E0010",
+            VB6ErrorKind::NoEqualSplit => "VB6E0011",
+            VB6ErrorKind::ResourceFileNameUnparsable => "VB6E0012",
+            VB6ErrorKind::NoColonForOffsetSplit => "VB6E0013",
+            VB6ErrorKind::NoKeyValueDividerFound => "VB6E0014",
+            VB6ErrorKind::Unparseable => "VB6E0015",
+            VB6ErrorKind::MajorVersionUnparseable => "VB6E0016",
+            VB6ErrorKind::DllBaseAddressUnparseable => "VB6E0017",
+            VB6ErrorKind::StartupUnparseable => "VB6E0018",
+            VB6ErrorKind::NameUnparseable => "VB6E0019",
+            VB6ErrorKind::CommandLineUnparseable => "VB6E0020",
+            VB6ErrorKind::HelpContextIdUnparseable => "VB6E0021",
+            VB6ErrorKind::MinorVersionUnparseable => "VB6E0022",
+            VB6ErrorKind::RevisionVersionUnparseable => "VB6E0023",
+            VB6ErrorKind::ThreadingModelUnparseable => "VB6E0024",
+            VB6ErrorKind::ThreadingModelInvalid => "VB6E0025",
+            VB6ErrorKind::NoPropertyName => "VB6E0026",
+            VB6ErrorKind::RelatedDocLineUnparseable => "VB6E0027",
+            VB6ErrorKind::AutoIncrementUnparseable => "VB6E0028",
+            VB6ErrorKind::CompatibilityModeUnparseable => "VB6E0029",
+            VB6ErrorKind::NoControlUpgradeUnparsable => "VB6E0030",
+            VB6ErrorKind::ServerSupportFilesUnparseable => "VB6E0031",
+            VB6ErrorKind::CommentUnparseable => "VB6E0032",
+            VB6ErrorKind::PropertyPageUnparseable => "VB6E0033",
+            VB6ErrorKind::CompilationTypeUnparseable => "VB6E0034",
+            VB6ErrorKind::OptimizationTypeUnparseable => "VB6E0035",
+            VB6ErrorKind::FavorPentiumProUnparseable => "VB6E0036",
+            VB6ErrorKind::DesignerLineUnparseable => "VB6E0037",
+            VB6ErrorKind::FormLineUnparseable => "VB6E0038",
+            VB6ErrorKind::UserControlLineUnparseable => "VB6E0039",
+            VB6ErrorKind::UserDocumentLineUnparseable => "VB6E0040",
+            VB6ErrorKind::PeriodExpectedInVersionNumber => "VB6E0041",
+            VB6ErrorKind::CodeViewDebugInfoUnparseable => "VB6E0042",
+            VB6ErrorKind::NoAliasingUnparseable => "VB6E0043",
+            VB6ErrorKind::UnusedControlInfoUnparseable => "VB6E0044",
+            VB6ErrorKind::BoundsCheckUnparseable => "VB6E0045",
+            VB6ErrorKind::OverflowCheckUnparseable => "VB6E0046",
+            VB6ErrorKind::FlPointCheckUnparseable => "VB6E0047",
+            VB6ErrorKind::FDIVCheckUnparseable => "VB6E0048",
+            VB6ErrorKind::UnroundedFPUnparseable => "VB6E0049",
+            VB6ErrorKind::StartModeUnparseable => "VB6E0050",
+            VB6ErrorKind::UnattendedUnparseable => "VB6E0051",
+            VB6ErrorKind::RetainedUnparseable => "VB6E0052",
+            VB6ErrorKind::ShortCutUnparseable => "VB6E0053",
+            VB6ErrorKind::DebugStartupOptionUnparseable => "VB6E0054",
+            VB6ErrorKind::UseExistingBrowserUnparseable => "VB6E0055",
+            VB6ErrorKind::AutoRefreshUnparseable => "VB6E0056",
+            VB6ErrorKind::ConnectionTypeUnparseable => "VB6E0057",
+            VB6ErrorKind::ThreadPerObjectUnparseable => "VB6E0058",
+            VB6ErrorKind::UnknownAttribute => "VB6E0059",
+            VB6ErrorKind::Header => "VB6E0060",
+            VB6ErrorKind::MissingNameAttribute => "VB6E0061",
+            VB6ErrorKind::KeywordNotFound => "VB6E0062",
+            VB6ErrorKind::TrueFalseOneZeroNegOneUnparseable => "VB6E0063",
+            VB6ErrorKind::FileContent => "VB6E0064",
+            VB6ErrorKind::MaxThreadsUnparseable => "VB6E0065",
+            VB6ErrorKind::NoEndProperty => "VB6E0066",
+            VB6ErrorKind::NoLineEndingAfterEndProperty => "VB6E0067",
+            VB6ErrorKind::NoNamespaceAfterBegin => "VB6E0068",
+            VB6ErrorKind::NoDotAfterNamespace => "VB6E0069",
+            VB6ErrorKind::NoUserControlNameAfterDot => "VB6E0070",
+            VB6ErrorKind::NoSpaceAfterControlKind => "VB6E0071",
+            VB6ErrorKind::NoControlNameAfterControlKind => "VB6E0072",
+            VB6ErrorKind::NoLineEndingAfterControlName => "VB6E0073",
+            VB6ErrorKind::UnknownToken => "VB6E0074",
+            VB6ErrorKind::TitleUnparseable => "VB6E0075",
+            VB6ErrorKind::UnknownProperty => "VB6E0076",
+            VB6ErrorKind::InvalidPropertyValueZeroNegOne => "VB6E0077",
+            VB6ErrorKind::InvalidPropertyValueTrueFalse => "VB6E0078",
+            VB6ErrorKind::HexColorParseError => "VB6E0079",
+            VB6ErrorKind::UnknownControlKind => "VB6E0080",
+            VB6ErrorKind::PropertyNameAsciiConversionError => "VB6E0081",
+            VB6ErrorKind::UnterminatedString => "VB6E0082",
+            VB6ErrorKind::StringParseError => "VB6E0083",
+            VB6ErrorKind::PropertyValueAsciiConversionError => "VB6E0084",
+            VB6ErrorKind::KeyValueParseError => "VB6E0085",
+            VB6ErrorKind::NamespaceAsciiConversionError => "VB6E0086",
+            VB6ErrorKind::ControlKindAsciiConversionError => "VB6E0087",
+            VB6ErrorKind::QualifiedControlNameAsciiConversionError => "VB6E0088",
+            VB6ErrorKind::AppearancePropertyInvalid => "VB6E0089",
+            VB6ErrorKind::BorderStylePropertyInvalid => "VB6E0090",
+            VB6ErrorKind::ClipControlsPropertyInvalid => "VB6E0091",
+            VB6ErrorKind::DragModePropertyInvalid => "VB6E0092",
+            VB6ErrorKind::EnabledPropertyInvalid => "VB6E0093",
+            VB6ErrorKind::MousePointerPropertyInvalid => "VB6E0094",
+            VB6ErrorKind::OLEDropModePropertyInvalid => "VB6E0095",
+            VB6ErrorKind::RightToLeftPropertyInvalid => "VB6E0096",
+            VB6ErrorKind::VisiblePropertyInvalid => "VB6E0097",
+            VB6ErrorKind::VariableNameTooLong => "VB6E0098",
+            VB6ErrorKind::InternalParseError => "VB6E0099",
+            VB6ErrorKind::TerminatorNotFound => "VB6E0100",
+            VB6ErrorKind::UnterminatedControl { .. } => "VB6E0101",
+            VB6ErrorKind::ObjectVersionMissing => "VB6E0102",
+            VB6ErrorKind::ObjectVersionInvalid => "VB6E0103",
+            VB6ErrorKind::UnexpectedRootControl { .. } => "VB6E0104",
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -365,6 +510,7 @@ impl Display for VB6Error {
             self.file_name.clone(),
             self.source_offset,
         )
+        .with_code(self.kind.code())
         .with_message("Parsing error")
         .with_label(kind_label)
         .finish()
@@ -407,3 +553,140 @@ impl<'a> From<ParseError<VB6Stream<'a>, ContextError>> for VB6ErrorKind {
         VB6ErrorKind::InternalParseError
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_are_stable() {
+        assert_eq!(
+            VB6ErrorKind::LikelyNonEnglishCharacterSet.code(),
+            "VB6E0001"
+        );
+        assert_eq!(VB6ErrorKind::LineTypeUnknown.code(), "VB6E0005");
+        assert_eq!(VB6ErrorKind::InternalParseError.code(), "VB6E0099");
+    }
+
+    #[test]
+    fn error_codes_are_distinct() {
+        let kinds = [
+            VB6ErrorKind::LikelyNonEnglishCharacterSet,
+            VB6ErrorKind::ReferenceExtraSections,
+            VB6ErrorKind::ReferenceMissingSections,
+            VB6ErrorKind::FirstLineNotProject,
+            VB6ErrorKind::LineTypeUnknown,
+            VB6ErrorKind::ProjectTypeUnknown,
+            VB6ErrorKind::NoLineEnding,
+            VB6ErrorKind::UnableToParseUuid,
+            VB6ErrorKind::PropertyNameUnparsable,
+            VB6ErrorKind::NoSemicolonSplit,
+            VB6ErrorKind::NoEqualSplit,
+            VB6ErrorKind::ResourceFileNameUnparsable,
+            VB6ErrorKind::NoColonForOffsetSplit,
+            VB6ErrorKind::NoKeyValueDividerFound,
+            VB6ErrorKind::Unparseable,
+            VB6ErrorKind::MajorVersionUnparseable,
+            VB6ErrorKind::DllBaseAddressUnparseable,
+            VB6ErrorKind::StartupUnparseable,
+            VB6ErrorKind::NameUnparseable,
+            VB6ErrorKind::CommandLineUnparseable,
+            VB6ErrorKind::HelpContextIdUnparseable,
+            VB6ErrorKind::MinorVersionUnparseable,
+            VB6ErrorKind::RevisionVersionUnparseable,
+            VB6ErrorKind::ThreadingModelUnparseable,
+            VB6ErrorKind::ThreadingModelInvalid,
+            VB6ErrorKind::NoPropertyName,
+            VB6ErrorKind::RelatedDocLineUnparseable,
+            VB6ErrorKind::AutoIncrementUnparseable,
+            VB6ErrorKind::CompatibilityModeUnparseable,
+            VB6ErrorKind::NoControlUpgradeUnparsable,
+            VB6ErrorKind::ServerSupportFilesUnparseable,
+            VB6ErrorKind::CommentUnparseable,
+            VB6ErrorKind::PropertyPageUnparseable,
+            VB6ErrorKind::CompilationTypeUnparseable,
+            VB6ErrorKind::OptimizationTypeUnparseable,
+            VB6ErrorKind::FavorPentiumProUnparseable,
+            VB6ErrorKind::DesignerLineUnparseable,
+            VB6ErrorKind::FormLineUnparseable,
+            VB6ErrorKind::UserControlLineUnparseable,
+            VB6ErrorKind::UserDocumentLineUnparseable,
+            VB6ErrorKind::PeriodExpectedInVersionNumber,
+            VB6ErrorKind::CodeViewDebugInfoUnparseable,
+            VB6ErrorKind::NoAliasingUnparseable,
+            VB6ErrorKind::UnusedControlInfoUnparseable,
+            VB6ErrorKind::BoundsCheckUnparseable,
+            VB6ErrorKind::OverflowCheckUnparseable,
+            VB6ErrorKind::FlPointCheckUnparseable,
+            VB6ErrorKind::FDIVCheckUnparseable,
+            VB6ErrorKind::UnroundedFPUnparseable,
+            VB6ErrorKind::StartModeUnparseable,
+            VB6ErrorKind::UnattendedUnparseable,
+            VB6ErrorKind::RetainedUnparseable,
+            VB6ErrorKind::ShortCutUnparseable,
+            VB6ErrorKind::DebugStartupOptionUnparseable,
+            VB6ErrorKind::UseExistingBrowserUnparseable,
+            VB6ErrorKind::AutoRefreshUnparseable,
+            VB6ErrorKind::ConnectionTypeUnparseable,
+            VB6ErrorKind::ThreadPerObjectUnparseable,
+            VB6ErrorKind::UnknownAttribute,
+            VB6ErrorKind::Header,
+            VB6ErrorKind::MissingNameAttribute,
+            VB6ErrorKind::KeywordNotFound,
+            VB6ErrorKind::TrueFalseOneZeroNegOneUnparseable,
+            VB6ErrorKind::FileContent,
+            VB6ErrorKind::MaxThreadsUnparseable,
+            VB6ErrorKind::NoEndProperty,
+            VB6ErrorKind::NoLineEndingAfterEndProperty,
+            VB6ErrorKind::NoNamespaceAfterBegin,
+            VB6ErrorKind::NoDotAfterNamespace,
+            VB6ErrorKind::NoUserControlNameAfterDot,
+            VB6ErrorKind::NoSpaceAfterControlKind,
+            VB6ErrorKind::NoControlNameAfterControlKind,
+            VB6ErrorKind::NoLineEndingAfterControlName,
+            VB6ErrorKind::UnknownToken,
+            VB6ErrorKind::TitleUnparseable,
+            VB6ErrorKind::UnknownProperty,
+            VB6ErrorKind::InvalidPropertyValueZeroNegOne,
+            VB6ErrorKind::InvalidPropertyValueTrueFalse,
+            VB6ErrorKind::HexColorParseError,
+            VB6ErrorKind::UnknownControlKind,
+            VB6ErrorKind::PropertyNameAsciiConversionError,
+            VB6ErrorKind::UnterminatedString,
+            VB6ErrorKind::StringParseError,
+            VB6ErrorKind::PropertyValueAsciiConversionError,
+            VB6ErrorKind::KeyValueParseError,
+            VB6ErrorKind::NamespaceAsciiConversionError,
+            VB6ErrorKind::ControlKindAsciiConversionError,
+            VB6ErrorKind::QualifiedControlNameAsciiConversionError,
+            VB6ErrorKind::AppearancePropertyInvalid,
+            VB6ErrorKind::BorderStylePropertyInvalid,
+            VB6ErrorKind::ClipControlsPropertyInvalid,
+            VB6ErrorKind::DragModePropertyInvalid,
+            VB6ErrorKind::EnabledPropertyInvalid,
+            VB6ErrorKind::MousePointerPropertyInvalid,
+            VB6ErrorKind::OLEDropModePropertyInvalid,
+            VB6ErrorKind::RightToLeftPropertyInvalid,
+            VB6ErrorKind::VisiblePropertyInvalid,
+            VB6ErrorKind::VariableNameTooLong,
+            VB6ErrorKind::InternalParseError,
+            VB6ErrorKind::TerminatorNotFound,
+            VB6ErrorKind::UnterminatedControl {
+                name: String::new(),
+            },
+            VB6ErrorKind::ObjectVersionMissing,
+            VB6ErrorKind::ObjectVersionInvalid,
+            VB6ErrorKind::UnexpectedRootControl {
+                file_kind: "",
+                found: String::new(),
+            },
+        ];
+
+        let codes = kinds
+            .iter()
+            .map(VB6ErrorKind::code)
+            .collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(codes.len(), kinds.len());
+    }
+}
@@ -16,11 +16,38 @@ use crate::errors::VB6ErrorKind;
 pub struct VB6MenuControl<'a> {
     pub name: &'a BStr,
     pub tag: &'a BStr,
-    pub index: i32,
+    /// The `Index` of the menu within its menu control array, or `None` if
+    /// the menu is not part of a control array.
+    pub index: Option<i32>,
     pub properties: MenuProperties<'a>,
     pub sub_menus: Vec<VB6MenuControl<'a>>,
 }
 
+impl VB6MenuControl<'_> {
+    /// Returns the name of the procedure which handles the given event for
+    /// this menu, such as `mnuFile_Click`.
+    ///
+    /// All members of a menu control array share one handler, which VB6
+    /// passes the `Index` of the member that raised the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The name of the event, such as `Click`.
+    #[must_use]
+    pub fn event_handler_name(&self, event: &str) -> String {
+        format!("{}_{event}", self.name)
+    }
+
+    /// Returns true if the menu is a member of a menu control array.
+    ///
+    /// The event handlers of a control array member take an extra leading
+    /// `Index As Integer` parameter.
+    #[must_use]
+    pub fn is_array_member(&self) -> bool {
+        self.index.is_some()
+    }
+}
+
 /// Properties for a Menu control.
 ///
 /// This is used as an enum variant of
@@ -27,7 +27,7 @@ use bstr::BStr;
 use num_enum::TryFromPrimitive;
 use serde::Serialize;
 
-use crate::parsers::form::VB6PropertyGroup;
+use crate::parsers::form::{build_i32_property, VB6PropertyGroup};
 
 use crate::language::controls::{
     checkbox::CheckBoxProperties,
@@ -94,7 +94,9 @@ pub enum StartUpPosition {
 pub struct VB6Control<'a> {
     pub name: &'a BStr,
     pub tag: &'a BStr,
-    pub index: i32,
+    /// The `Index` of the control within its control array, or `None` if
+    /// the control is not part of a control array.
+    pub index: Option<i32>,
     pub kind: VB6ControlKind<'a>,
 }
 
@@ -178,12 +180,198 @@ pub enum VB6ControlKind<'a> {
         controls: Vec<VB6Control<'a>>,
         menus: Vec<VB6MenuControl<'a>>,
     },
+    /// The root control of a user control (`.ctl`) file.
+    ///
+    /// The properties of a user control are kept as the raw name and value
+    /// pairs found in the file.
+    UserControl {
+        properties: HashMap<&'a BStr, &'a BStr>,
+        property_groups: Vec<VB6PropertyGroup<'a>>,
+        controls: Vec<VB6Control<'a>>,
+        menus: Vec<VB6MenuControl<'a>>,
+    },
+    /// The root control of a property page (`.pag`) file.
+    ///
+    /// Like a user control, the properties of a property page are kept as
+    /// the raw name and value pairs found in the file.
+    PropertyPage {
+        properties: HashMap<&'a BStr, &'a BStr>,
+        property_groups: Vec<VB6PropertyGroup<'a>>,
+        controls: Vec<VB6Control<'a>>,
+    },
+    /// A control which is not built into VB6, such as a control from an
+    /// `.ocx` file.
+    ///
+    /// The properties of a custom control are kept as the raw name and value
+    /// pairs found in the file.
     Custom {
+        /// The class of the control including its library, as written after
+        /// `Begin`, e.g. `MSComctlLib.ListView`.
+        class: &'a BStr,
         properties: HashMap<&'a BStr, &'a BStr>,
         property_groups: Vec<VB6PropertyGroup<'a>>,
     },
 }
 
+/// The number of pixels per inch Windows uses at 100% display scaling.
+pub const DEFAULT_DPI: u32 = 96;
+
+impl VB6Control<'_> {
+    /// Returns the scale mode this control uses for the position and size of
+    /// the controls it contains.
+    ///
+    /// Only forms and picture boxes have a `ScaleMode` property. `None` is
+    /// returned for every other kind of control.
+    #[must_use]
+    pub fn scale_mode(&self) -> Option<&ScaleMode> {
+        match &self.kind {
+            VB6ControlKind::Form { properties, .. } => Some(&properties.scale_mode),
+            VB6ControlKind::PictureBox { properties } => Some(&properties.scale_mode),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the control is one of the controls built into VB6,
+    /// written as `VB.<kind>` in a form file.
+    ///
+    /// Controls from other libraries, such as `MSComctlLib.ListView`, are
+    /// parsed as [`VB6ControlKind::Custom`].
+    #[must_use]
+    pub fn is_vb_intrinsic(&self) -> bool {
+        !matches!(self.kind, VB6ControlKind::Custom { .. })
+    }
+
+    /// Returns the name of the procedure which handles the given event for
+    /// this control, such as `Command1_Click`.
+    ///
+    /// The root object of a file is named by its kind rather than its name,
+    /// so the handlers of a form are `Form_Load` and so on. All members of
+    /// a control array share one handler, which VB6 passes the `Index` of
+    /// the member that raised the event as its first parameter. See
+    /// [`VB6Control::is_array_member`].
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The name of the event, such as `Click`.
+    #[must_use]
+    pub fn event_handler_name(&self, event: &str) -> String {
+        let object = match self.kind {
+            VB6ControlKind::Form { .. } => "Form".into(),
+            VB6ControlKind::MDIForm { .. } => "MDIForm".into(),
+            VB6ControlKind::UserControl { .. } => "UserControl".into(),
+            VB6ControlKind::PropertyPage { .. } => "PropertyPage".into(),
+            _ => self.name.to_string(),
+        };
+
+        format!("{object}_{event}")
+    }
+
+    /// Returns true if the control is a member of a control array.
+    ///
+    /// The event handlers of a control array member take an extra leading
+    /// `Index As Integer` parameter.
+    #[must_use]
+    pub fn is_array_member(&self) -> bool {
+        self.index.is_some()
+    }
+
+    /// Returns the `Left` of the control in pixels.
+    ///
+    /// The position of a control is stored in the scale mode of its
+    /// container, so `scale_mode` should be the scale mode of the form or
+    /// picture box the control sits in. A form's own position is always in
+    /// twips, so use `ScaleMode::Twip` for the form itself.
+    ///
+    /// Returns `None` if the control has no `Left` or the scale mode cannot
+    /// be converted to pixels.
+    #[must_use]
+    pub fn left_pixels(&self, scale_mode: &ScaleMode, dpi: u32) -> Option<i32> {
+        scale_mode.to_pixels(self.bounds().0?, dpi)
+    }
+
+    /// Returns the `Top` of the control in pixels.
+    ///
+    /// See [`VB6Control::left_pixels`] for which scale mode to pass.
+    #[must_use]
+    pub fn top_pixels(&self, scale_mode: &ScaleMode, dpi: u32) -> Option<i32> {
+        scale_mode.to_pixels(self.bounds().1?, dpi)
+    }
+
+    /// Returns the `Width` of the control in pixels.
+    ///
+    /// See [`VB6Control::left_pixels`] for which scale mode to pass.
+    #[must_use]
+    pub fn width_pixels(&self, scale_mode: &ScaleMode, dpi: u32) -> Option<i32> {
+        scale_mode.to_pixels(self.bounds().2?, dpi)
+    }
+
+    /// Returns the `Height` of the control in pixels.
+    ///
+    /// See [`VB6Control::left_pixels`] for which scale mode to pass.
+    #[must_use]
+    pub fn height_pixels(&self, scale_mode: &ScaleMode, dpi: u32) -> Option<i32> {
+        scale_mode.to_pixels(self.bounds().3?, dpi)
+    }
+
+    /// The `Left`, `Top`, `Width` and `Height` of the control, in the units
+    /// they were written in.
+    fn bounds(&self) -> (Option<i32>, Option<i32>, Option<i32>, Option<i32>) {
+        macro_rules! bounds {
+            ($properties:expr) => {
+                (
+                    Some($properties.left),
+                    Some($properties.top),
+                    Some($properties.width),
+                    Some($properties.height),
+                )
+            };
+        }
+
+        match &self.kind {
+            VB6ControlKind::CommandButton { properties } => bounds!(properties),
+            VB6ControlKind::Data { properties } => bounds!(properties),
+            VB6ControlKind::TextBox { properties } => bounds!(properties),
+            VB6ControlKind::CheckBox { properties } => bounds!(properties),
+            VB6ControlKind::Shape { properties } => bounds!(properties),
+            VB6ControlKind::ListBox { properties } => bounds!(properties),
+            VB6ControlKind::Label { properties } => bounds!(properties),
+            VB6ControlKind::Frame { properties, .. } => bounds!(properties),
+            VB6ControlKind::PictureBox { properties } => bounds!(properties),
+            VB6ControlKind::FileListBox { properties } => bounds!(properties),
+            VB6ControlKind::DriveListBox { properties } => bounds!(properties),
+            VB6ControlKind::DirListBox { properties } => bounds!(properties),
+            VB6ControlKind::Ole { properties } => bounds!(properties),
+            VB6ControlKind::OptionButton { properties } => bounds!(properties),
+            VB6ControlKind::Image { properties } => bounds!(properties),
+            VB6ControlKind::ComboBox { properties } => bounds!(properties),
+            VB6ControlKind::HScrollBar { properties }
+            | VB6ControlKind::VScrollBar { properties } => bounds!(properties),
+            VB6ControlKind::Form { properties, .. } => bounds!(properties),
+            VB6ControlKind::MDIForm { properties, .. } => bounds!(properties),
+            VB6ControlKind::Timer { properties } => {
+                (Some(properties.left), Some(properties.top), None, None)
+            }
+            VB6ControlKind::UserControl { properties, .. }
+            | VB6ControlKind::PropertyPage { properties, .. }
+            | VB6ControlKind::Custom { properties, .. } => {
+                let property = |name: &[u8]| {
+                    properties
+                        .contains_key(BStr::new(name))
+                        .then(|| build_i32_property(properties, &name, 0))
+                };
+
+                (
+                    property(b"Left"),
+                    property(b"Top"),
+                    property(b"Width"),
+                    property(b"Height"),
+                )
+            }
+            VB6ControlKind::Line { .. } | VB6ControlKind::Menu { .. } => (None, None, None, None),
+        }
+    }
+}
+
 impl<'a> VB6ControlKind<'a> {
     #[must_use]
     pub fn is_menu(&self) -> bool {
@@ -475,6 +663,38 @@ pub enum ScaleMode {
     Centimeter = 7,
 }
 
+impl ScaleMode {
+    /// Converts a value in this scale mode into pixels at the given DPI.
+    ///
+    /// Returns `None` for `User`, which depends on the container's
+    /// `ScaleWidth` and `ScaleHeight`, and for `Character`, which is a
+    /// different size horizontally and vertically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::language::{ScaleMode, DEFAULT_DPI};
+    ///
+    /// assert_eq!(ScaleMode::Twip.to_pixels(1440, DEFAULT_DPI), Some(96));
+    /// assert_eq!(ScaleMode::Pixel.to_pixels(32, DEFAULT_DPI), Some(32));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pixels(&self, value: i32, dpi: u32) -> Option<i32> {
+        let units_per_inch = match self {
+            ScaleMode::User | ScaleMode::Character => return None,
+            ScaleMode::Pixel => return Some(value),
+            ScaleMode::Twip => 1440.0,
+            ScaleMode::Point => 72.0,
+            ScaleMode::Inches => 1.0,
+            ScaleMode::Millimeter => 25.4,
+            ScaleMode::Centimeter => 2.54,
+        };
+
+        Some((f64::from(value) * f64::from(dpi) / units_per_inch).round() as i32)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Default, TryFromPrimitive)]
 #[repr(i32)]
 pub enum SizeMode {
@@ -0,0 +1,226 @@
+/// The reserved words of VB6, in alphabetical order.
+///
+/// These are the words the language itself claims, which cannot be used as
+/// the name of a variable, procedure, or type. Along with the statement and
+/// operator keywords, this includes the built in functions and type names
+/// which are reserved, such as `Len`, `CStr`, and `Debug`.
+const RESERVED_WORDS: &[&str] = &[
+    "Abs",
+    "AddressOf",
+    "And",
+    "Any",
+    "Array",
+    "As",
+    "Boolean",
+    "ByRef",
+    "Byte",
+    "ByVal",
+    "Call",
+    "Case",
+    "CBool",
+    "CByte",
+    "CCur",
+    "CDate",
+    "CDbl",
+    "CDec",
+    "CInt",
+    "Circle",
+    "CLng",
+    "Close",
+    "Const",
+    "CSng",
+    "CStr",
+    "Currency",
+    "CVar",
+    "CVErr",
+    "Date",
+    "Debug",
+    "Decimal",
+    "Declare",
+    "DefBool",
+    "DefByte",
+    "DefCur",
+    "DefDate",
+    "DefDbl",
+    "DefDec",
+    "DefInt",
+    "DefLng",
+    "DefObj",
+    "DefSng",
+    "DefStr",
+    "DefVar",
+    "Dim",
+    "Do",
+    "DoEvents",
+    "Double",
+    "Each",
+    "Else",
+    "ElseIf",
+    "Empty",
+    "End",
+    "EndIf",
+    "Enum",
+    "Eqv",
+    "Erase",
+    "Event",
+    "Exit",
+    "False",
+    "Fix",
+    "For",
+    "Friend",
+    "Function",
+    "Get",
+    "Global",
+    "GoSub",
+    "GoTo",
+    "If",
+    "Imp",
+    "Implements",
+    "In",
+    "Input",
+    "InputB",
+    "Int",
+    "Integer",
+    "Is",
+    "LBound",
+    "Len",
+    "LenB",
+    "Let",
+    "Like",
+    "Lock",
+    "Long",
+    "Loop",
+    "LSet",
+    "Me",
+    "Mod",
+    "New",
+    "Next",
+    "Not",
+    "Nothing",
+    "Null",
+    "Object",
+    "On",
+    "Open",
+    "Option",
+    "Optional",
+    "Or",
+    "ParamArray",
+    "Preserve",
+    "Print",
+    "Private",
+    "Property",
+    "PSet",
+    "Public",
+    "Put",
+    "RaiseEvent",
+    "ReDim",
+    "Rem",
+    "Resume",
+    "Return",
+    "RSet",
+    "Scale",
+    "Seek",
+    "Select",
+    "Set",
+    "Sgn",
+    "Shared",
+    "Single",
+    "Spc",
+    "Static",
+    "Step",
+    "Stop",
+    "String",
+    "Sub",
+    "Tab",
+    "Then",
+    "To",
+    "True",
+    "Type",
+    "TypeOf",
+    "UBound",
+    "Unlock",
+    "Until",
+    "Variant",
+    "Wend",
+    "While",
+    "With",
+    "WithEvents",
+    "Write",
+    "Xor",
+];
+
+/// Returns the reserved words of VB6 with their canonical spelling.
+///
+/// The words are in alphabetical order, ignoring case.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::language::reserved_words;
+///
+/// assert!(reserved_words().contains(&"WithEvents"));
+/// assert!(!reserved_words().contains(&"Counter"));
+/// ```
+#[must_use]
+pub fn reserved_words() -> &'static [&'static str] {
+    RESERVED_WORDS
+}
+
+/// Returns true if the word is a VB6 reserved word.
+///
+/// VB6 is not case sensitive, so neither is this check.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::language::is_reserved_word;
+///
+/// assert!(is_reserved_word("gosub"));
+/// assert!(is_reserved_word("Implements"));
+/// assert!(!is_reserved_word("Counter"));
+/// ```
+#[must_use]
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED_WORDS
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement_keywords_are_reserved() {
+        for word in [
+            "Property",
+            "AddressOf",
+            "Event",
+            "Open",
+            "Close",
+            "Print",
+            "Put",
+            "Input",
+            "Lock",
+            "Unlock",
+            "Seek",
+            "Write",
+            "Declare",
+            "RaiseEvent",
+            "ReDim",
+            "Friend",
+        ] {
+            assert!(is_reserved_word(word), "{word} should be reserved");
+        }
+
+        assert!(!is_reserved_word("Counter"));
+        assert!(!is_reserved_word("Form_Load"));
+    }
+
+    #[test]
+    fn reserved_words_are_sorted() {
+        assert!(reserved_words()
+            .windows(2)
+            .all(|pair| pair[0].to_lowercase() < pair[1].to_lowercase()));
+    }
+}
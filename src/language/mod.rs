@@ -1,5 +1,6 @@
 mod color;
 mod controls;
+mod keywords;
 mod tokens;
 
 pub use color::{
@@ -38,6 +39,9 @@ pub use controls::{
     Align, Alignment, Appearance, BackStyle, BorderStyle, ClipControls, DragMode, DrawMode,
     DrawStyle, FillStyle, FormLinkMode, JustifyAlignment, LinkMode, MousePointer, OLEDragMode,
     OLEDropMode, ScaleMode, StartUpPosition, Style, VB6Control, VB6ControlKind, WindowState,
+    DEFAULT_DPI,
 };
 
-pub use tokens::VB6Token;
+pub use keywords::{is_reserved_word, reserved_words};
+
+pub use tokens::{VB6Comment, VB6CommentKind, VB6Token};
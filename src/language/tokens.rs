@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use bstr::BStr;
 
 /// Represents a VB6 token.
@@ -15,6 +17,9 @@ pub enum VB6Token<'a> {
     /// Represents a comment.
     /// Includes the single quote character.
     Comment(&'a BStr),
+    /// Represents a comment started with the `Rem` keyword.
+    /// Includes the `Rem` keyword.
+    RemComment(&'a BStr),
 
     ReDimKeyword(&'a BStr),
     DimKeyword(&'a BStr),
@@ -25,6 +30,10 @@ pub enum VB6Token<'a> {
     OptionKeyword(&'a BStr),
     ExplicitKeyword(&'a BStr),
 
+    /// Represents the `Implements` keyword used by a class to declare an
+    /// interface it implements.
+    ImplementsKeyword(&'a BStr),
+
     PrivateKeyword(&'a BStr),
     PublicKeyword(&'a BStr),
 
@@ -56,6 +65,9 @@ pub enum VB6Token<'a> {
     /// Represents a string literal.
     /// The string literal is enclosed in double quotes.
     StringLiteral(&'a BStr),
+    /// Represents a date literal such as `#1/1/2000 12:00:00 AM#`.
+    /// The date literal is enclosed in octothorpes.
+    DateLiteral(&'a BStr),
 
     IfKeyword(&'a BStr),
     ElseKeyword(&'a BStr),
@@ -129,9 +141,280 @@ pub enum VB6Token<'a> {
 
     /// Represents a variable name.
     /// This is a name that starts with a letter and can contain letters, numbers, and underscores.
+    /// A trailing type-declaration character (`$`, `%`, `&`, `!`, `#`, or `@`)
+    /// is included as part of the name.
     VariableName(&'a BStr),
     /// Represents a number.
-    /// This is just a collection of digits and hasn't been parsed into a
-    /// specific kind of number yet.
+    /// This covers integers and floating point numbers such as `.5` and
+    /// `1.5E-3`, including any trailing type-declaration character. It
+    /// hasn't been parsed into a specific kind of number yet.
     Number(&'a BStr),
 }
+
+impl<'a> VB6Token<'a> {
+    /// Returns the source text of the token, exactly as it was written.
+    ///
+    /// Every token, including whitespace, newlines, and comments, keeps the
+    /// bytes it was parsed from, so the text of all the tokens of a file put
+    /// back together is the original source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::language::VB6Token;
+    ///
+    /// assert_eq!(VB6Token::SubKeyword("sub".into()).text(), "sub");
+    /// assert_eq!(VB6Token::Newline("\r\n".into()).text(), "\r\n");
+    /// ```
+    #[must_use]
+    pub fn text(&self) -> &'a BStr {
+        match self {
+            VB6Token::Whitespace(text)
+            | VB6Token::Newline(text)
+            | VB6Token::Comment(text)
+            | VB6Token::RemComment(text)
+            | VB6Token::ReDimKeyword(text)
+            | VB6Token::DimKeyword(text)
+            | VB6Token::DeclareKeyword(text)
+            | VB6Token::LibKeyword(text)
+            | VB6Token::WithKeyword(text)
+            | VB6Token::OptionKeyword(text)
+            | VB6Token::ExplicitKeyword(text)
+            | VB6Token::ImplementsKeyword(text)
+            | VB6Token::PrivateKeyword(text)
+            | VB6Token::PublicKeyword(text)
+            | VB6Token::ConstKeyword(text)
+            | VB6Token::AsKeyword(text)
+            | VB6Token::ByValKeyword(text)
+            | VB6Token::ByRefKeyword(text)
+            | VB6Token::OptionalKeyword(text)
+            | VB6Token::FunctionKeyword(text)
+            | VB6Token::SubKeyword(text)
+            | VB6Token::EndKeyword(text)
+            | VB6Token::TrueKeyword(text)
+            | VB6Token::FalseKeyword(text)
+            | VB6Token::EnumKeyword(text)
+            | VB6Token::TypeKeyword(text)
+            | VB6Token::BooleanKeyword(text)
+            | VB6Token::ByteKeyword(text)
+            | VB6Token::LongKeyword(text)
+            | VB6Token::SingleKeyword(text)
+            | VB6Token::StringKeyword(text)
+            | VB6Token::IntegerKeyword(text)
+            | VB6Token::StringLiteral(text)
+            | VB6Token::DateLiteral(text)
+            | VB6Token::IfKeyword(text)
+            | VB6Token::ElseKeyword(text)
+            | VB6Token::AndKeyword(text)
+            | VB6Token::OrKeyword(text)
+            | VB6Token::NotKeyword(text)
+            | VB6Token::ThenKeyword(text)
+            | VB6Token::GotoKeyword(text)
+            | VB6Token::ExitKeyword(text)
+            | VB6Token::ForKeyword(text)
+            | VB6Token::ToKeyword(text)
+            | VB6Token::StepKeyword(text)
+            | VB6Token::NextKeyword(text)
+            | VB6Token::DollarSign(text)
+            | VB6Token::Underscore(text)
+            | VB6Token::Ampersand(text)
+            | VB6Token::Percent(text)
+            | VB6Token::Octothorpe(text)
+            | VB6Token::LeftParanthesis(text)
+            | VB6Token::RightParanthesis(text)
+            | VB6Token::LeftSquareBracket(text)
+            | VB6Token::RightSquareBracket(text)
+            | VB6Token::Comma(text)
+            | VB6Token::Semicolon(text)
+            | VB6Token::AtSign(text)
+            | VB6Token::ExclamationMark(text)
+            | VB6Token::EqualityOperator(text)
+            | VB6Token::LessThanOperator(text)
+            | VB6Token::GreaterThanOperator(text)
+            | VB6Token::MultiplicationOperator(text)
+            | VB6Token::SubtractionOperator(text)
+            | VB6Token::AdditionOperator(text)
+            | VB6Token::DivisionOperator(text)
+            | VB6Token::BackwardSlashOperator(text)
+            | VB6Token::PeriodOperator(text)
+            | VB6Token::ColonOperator(text)
+            | VB6Token::ExponentiationOperator(text)
+            | VB6Token::VariableName(text)
+            | VB6Token::Number(text) => text,
+        }
+    }
+
+    /// Returns true if the token is a keyword.
+    ///
+    /// `True` and `False` are treated as literals rather than keywords.
+    #[must_use]
+    pub fn is_keyword(&self) -> bool {
+        matches!(
+            self,
+            VB6Token::ReDimKeyword(_)
+                | VB6Token::DimKeyword(_)
+                | VB6Token::DeclareKeyword(_)
+                | VB6Token::LibKeyword(_)
+                | VB6Token::WithKeyword(_)
+                | VB6Token::OptionKeyword(_)
+                | VB6Token::ExplicitKeyword(_)
+                | VB6Token::ImplementsKeyword(_)
+                | VB6Token::PrivateKeyword(_)
+                | VB6Token::PublicKeyword(_)
+                | VB6Token::ConstKeyword(_)
+                | VB6Token::AsKeyword(_)
+                | VB6Token::ByValKeyword(_)
+                | VB6Token::ByRefKeyword(_)
+                | VB6Token::OptionalKeyword(_)
+                | VB6Token::FunctionKeyword(_)
+                | VB6Token::SubKeyword(_)
+                | VB6Token::EndKeyword(_)
+                | VB6Token::EnumKeyword(_)
+                | VB6Token::TypeKeyword(_)
+                | VB6Token::BooleanKeyword(_)
+                | VB6Token::ByteKeyword(_)
+                | VB6Token::LongKeyword(_)
+                | VB6Token::SingleKeyword(_)
+                | VB6Token::StringKeyword(_)
+                | VB6Token::IntegerKeyword(_)
+                | VB6Token::IfKeyword(_)
+                | VB6Token::ElseKeyword(_)
+                | VB6Token::AndKeyword(_)
+                | VB6Token::OrKeyword(_)
+                | VB6Token::NotKeyword(_)
+                | VB6Token::ThenKeyword(_)
+                | VB6Token::GotoKeyword(_)
+                | VB6Token::ExitKeyword(_)
+                | VB6Token::ForKeyword(_)
+                | VB6Token::ToKeyword(_)
+                | VB6Token::StepKeyword(_)
+                | VB6Token::NextKeyword(_)
+        )
+    }
+
+    /// Returns true if the token is a literal value.
+    ///
+    /// This covers string literals, date literals, numbers, and the boolean
+    /// literals `True` and `False`.
+    #[must_use]
+    pub fn is_literal(&self) -> bool {
+        matches!(
+            self,
+            VB6Token::StringLiteral(_)
+                | VB6Token::DateLiteral(_)
+                | VB6Token::Number(_)
+                | VB6Token::TrueKeyword(_)
+                | VB6Token::FalseKeyword(_)
+        )
+    }
+
+    /// Returns true if the token is an operator.
+    ///
+    /// This covers the arithmetic and comparison operators along with `&`,
+    /// the string concatenation operator. The logical operators `And`, `Or`,
+    /// and `Not` are keywords and are not included. Neither are `.` and `:`,
+    /// which separate members and statements rather than operate on values.
+    #[must_use]
+    pub fn is_operator(&self) -> bool {
+        matches!(
+            self,
+            VB6Token::EqualityOperator(_)
+                | VB6Token::LessThanOperator(_)
+                | VB6Token::GreaterThanOperator(_)
+                | VB6Token::MultiplicationOperator(_)
+                | VB6Token::SubtractionOperator(_)
+                | VB6Token::AdditionOperator(_)
+                | VB6Token::DivisionOperator(_)
+                | VB6Token::BackwardSlashOperator(_)
+                | VB6Token::ExponentiationOperator(_)
+                | VB6Token::Ampersand(_)
+        )
+    }
+
+    /// Returns the canonical spelling of a keyword token.
+    ///
+    /// VB6 keywords are not case sensitive, and the IDE rewrites them with
+    /// the casing returned here, e.g. `end sub` becomes `End Sub`. The text
+    /// of the token is left as it was written in the source.
+    ///
+    /// # Returns
+    ///
+    /// The keyword as VB6 spells it, or `None` if the token is not a keyword.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::language::VB6Token;
+    ///
+    /// assert_eq!(VB6Token::SubKeyword("sub".into()).canonical(), Some("Sub"));
+    /// assert_eq!(VB6Token::VariableName("x".into()).canonical(), None);
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> Option<&'static str> {
+        match self {
+            VB6Token::ReDimKeyword(_) => Some("ReDim"),
+            VB6Token::DimKeyword(_) => Some("Dim"),
+            VB6Token::DeclareKeyword(_) => Some("Declare"),
+            VB6Token::LibKeyword(_) => Some("Lib"),
+            VB6Token::WithKeyword(_) => Some("With"),
+            VB6Token::OptionKeyword(_) => Some("Option"),
+            VB6Token::ExplicitKeyword(_) => Some("Explicit"),
+            VB6Token::ImplementsKeyword(_) => Some("Implements"),
+            VB6Token::PrivateKeyword(_) => Some("Private"),
+            VB6Token::PublicKeyword(_) => Some("Public"),
+            VB6Token::ConstKeyword(_) => Some("Const"),
+            VB6Token::AsKeyword(_) => Some("As"),
+            VB6Token::ByValKeyword(_) => Some("ByVal"),
+            VB6Token::ByRefKeyword(_) => Some("ByRef"),
+            VB6Token::OptionalKeyword(_) => Some("Optional"),
+            VB6Token::FunctionKeyword(_) => Some("Function"),
+            VB6Token::SubKeyword(_) => Some("Sub"),
+            VB6Token::EndKeyword(_) => Some("End"),
+            VB6Token::TrueKeyword(_) => Some("True"),
+            VB6Token::FalseKeyword(_) => Some("False"),
+            VB6Token::EnumKeyword(_) => Some("Enum"),
+            VB6Token::TypeKeyword(_) => Some("Type"),
+            VB6Token::BooleanKeyword(_) => Some("Boolean"),
+            VB6Token::ByteKeyword(_) => Some("Byte"),
+            VB6Token::LongKeyword(_) => Some("Long"),
+            VB6Token::SingleKeyword(_) => Some("Single"),
+            VB6Token::StringKeyword(_) => Some("String"),
+            VB6Token::IntegerKeyword(_) => Some("Integer"),
+            VB6Token::IfKeyword(_) => Some("If"),
+            VB6Token::ElseKeyword(_) => Some("Else"),
+            VB6Token::AndKeyword(_) => Some("And"),
+            VB6Token::OrKeyword(_) => Some("Or"),
+            VB6Token::NotKeyword(_) => Some("Not"),
+            VB6Token::ThenKeyword(_) => Some("Then"),
+            VB6Token::GotoKeyword(_) => Some("GoTo"),
+            VB6Token::ExitKeyword(_) => Some("Exit"),
+            VB6Token::ForKeyword(_) => Some("For"),
+            VB6Token::ToKeyword(_) => Some("To"),
+            VB6Token::StepKeyword(_) => Some("Step"),
+            VB6Token::NextKeyword(_) => Some("Next"),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of a VB6 comment.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize)]
+pub enum VB6CommentKind {
+    /// A comment which starts with a single quote.
+    EndOfLine,
+    /// A comment which starts with the `Rem` keyword.
+    Rem,
+}
+
+/// A comment found in VB6 code.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize)]
+pub struct VB6Comment<'a> {
+    /// The text of the comment without the leading `'` or `Rem`.
+    pub text: &'a BStr,
+    /// The byte range of the whole comment, including the `'` or `Rem`.
+    pub span: Range<usize>,
+    pub kind: VB6CommentKind,
+    /// True if the comment is on a line of its own, false if it follows
+    /// other code on the same line.
+    pub standalone: bool,
+}
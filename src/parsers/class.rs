@@ -211,6 +211,207 @@ impl<'a> VB6ClassFile<'a> {
 
         Ok(VB6ClassFile { header, tokens })
     }
+
+    /// Checks the class `VB_Name` against the name of the file it came from.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The file name or path of the class file.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file stem matches the `VB_Name` attribute, ignoring case.
+    #[must_use]
+    pub fn name_matches_file(&self, file_name: &str) -> bool {
+        self.header.attributes.name_matches_file(file_name)
+    }
+
+    /// Returns the names of the interfaces the class implements.
+    ///
+    /// An interface is implemented with an `Implements` line in the
+    /// declarations section of the class, before the first `Sub` or
+    /// `Function`. Any `Implements` lines after that point are not valid VB6
+    /// and are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The interface names in the order they appear in the class.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6ClassFile;
+    ///
+    /// let input = b"VERSION 1.0 CLASS
+    /// BEGIN
+    ///   MultiUse = -1  'True
+    /// END
+    /// Attribute VB_Name = \"Shape\"
+    /// Option Explicit
+    /// Implements IDrawable
+    /// ";
+    ///
+    /// let class = VB6ClassFile::parse("shape.cls".to_owned(), &mut input.as_slice()).unwrap();
+    ///
+    /// assert_eq!(class.implemented_interfaces(), vec!["IDrawable"]);
+    /// ```
+    #[must_use]
+    pub fn implemented_interfaces(&self) -> Vec<&'a BStr> {
+        let mut interfaces = vec![];
+
+        let lines = self
+            .tokens
+            .split(|token| matches!(token, VB6Token::Newline(_)));
+
+        for line in lines {
+            let mut line_tokens = line
+                .iter()
+                .filter(|token| !matches!(token, VB6Token::Whitespace(_)))
+                .skip_while(|token| {
+                    matches!(
+                        token,
+                        VB6Token::PublicKeyword(_) | VB6Token::PrivateKeyword(_)
+                    )
+                });
+
+            match (line_tokens.next(), line_tokens.next()) {
+                (Some(VB6Token::ImplementsKeyword(_)), Some(VB6Token::VariableName(name))) => {
+                    interfaces.push(*name);
+                }
+                (Some(VB6Token::SubKeyword(_) | VB6Token::FunctionKeyword(_)), _) => break,
+                _ => {}
+            }
+        }
+
+        interfaces
+    }
+
+    /// Returns the prefix VB6 gives the procedures implementing an interface.
+    ///
+    /// A class that `Implements IFoo` provides the `Bar` method of the
+    /// interface as a procedure named `IFoo_Bar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `interface` - The name of the interface.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6ClassFile;
+    ///
+    /// assert_eq!(VB6ClassFile::interface_method_prefix("IFoo"), "IFoo_");
+    /// ```
+    #[must_use]
+    pub fn interface_method_prefix(interface: &str) -> String {
+        format!("{interface}_")
+    }
+
+    /// Returns the procedures of the class grouped by the interface they
+    /// implement.
+    ///
+    /// A procedure belongs to an interface when its name starts with the
+    /// [`interface_method_prefix`](Self::interface_method_prefix) of one of
+    /// the [`implemented_interfaces`](Self::implemented_interfaces), ignoring
+    /// case. If more than one prefix matches, the longest one wins. `Sub`,
+    /// `Function` and `Property Get`/`Let`/`Set` procedures are included, so
+    /// a property shows up once for each of its accessors.
+    ///
+    /// # Returns
+    ///
+    /// Each implemented interface, in the order they are declared, along
+    /// with its procedure names in the order they appear in the class.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6ClassFile;
+    ///
+    /// let input = b"VERSION 1.0 CLASS
+    /// BEGIN
+    ///   MultiUse = -1  'True
+    /// END
+    /// Attribute VB_Name = \"Shape\"
+    /// Implements IDrawable
+    ///
+    /// Private Sub IDrawable_Draw()
+    /// End Sub
+    /// ";
+    ///
+    /// let class = VB6ClassFile::parse("shape.cls".to_owned(), &mut input.as_slice()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     class.interface_methods(),
+    ///     vec![("IDrawable".into(), vec!["IDrawable_Draw".into()])]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn interface_methods(&self) -> Vec<(&'a BStr, Vec<&'a BStr>)> {
+        let mut interfaces: Vec<(&'a BStr, Vec<&'a BStr>)> = self
+            .implemented_interfaces()
+            .into_iter()
+            .map(|interface| (interface, vec![]))
+            .collect();
+
+        let lines = self
+            .tokens
+            .split(|token| matches!(token, VB6Token::Newline(_)));
+
+        for line in lines {
+            let Some(name) = procedure_name(line) else {
+                continue;
+            };
+
+            let interface = interfaces
+                .iter_mut()
+                .filter(|(interface, _)| {
+                    let prefix = Self::interface_method_prefix(&interface.to_str_lossy());
+
+                    name.len() > prefix.len()
+                        && name[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+                })
+                .max_by_key(|(interface, _)| interface.len());
+
+            if let Some((_, methods)) = interface {
+                methods.push(name);
+            }
+        }
+
+        interfaces
+    }
+}
+
+/// Returns the name of the procedure declared on a line of tokens, if any.
+fn procedure_name<'a>(line: &[VB6Token<'a>]) -> Option<&'a BStr> {
+    let mut line_tokens = line
+        .iter()
+        .filter(|token| !matches!(token, VB6Token::Whitespace(_)))
+        .skip_while(|token| match token {
+            VB6Token::PublicKeyword(_) | VB6Token::PrivateKeyword(_) => true,
+            VB6Token::VariableName(name) => {
+                name.eq_ignore_ascii_case(b"Friend") || name.eq_ignore_ascii_case(b"Static")
+            }
+            _ => false,
+        });
+
+    match line_tokens.next()? {
+        VB6Token::SubKeyword(_) | VB6Token::FunctionKeyword(_) => {}
+        VB6Token::VariableName(keyword) if keyword.eq_ignore_ascii_case(b"Property") => {
+            match line_tokens.next()? {
+                VB6Token::VariableName(accessor)
+                    if [b"Get", b"Let", b"Set"]
+                        .iter()
+                        .any(|kind| accessor.eq_ignore_ascii_case(*kind)) => {}
+                _ => return None,
+            }
+        }
+        _ => return None,
+    }
+
+    match line_tokens.next()? {
+        VB6Token::VariableName(name) => Some(name),
+        _ => None,
+    }
 }
 
 /// Parses a VB6 class file from the header.
@@ -399,6 +600,41 @@ Option Explicit
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn implemented_interfaces() {
+        let input = b"VERSION 1.0 CLASS
+BEGIN
+    MultiUse = -1  'True
+END
+Attribute VB_Name = \"Something\"
+Option Explicit
+Implements IShape
+Implements IPrintable
+
+Private Sub IShape_Draw()
+End Sub
+";
+
+        let class = VB6ClassFile::parse("test.cls".to_owned(), &mut input.as_slice()).unwrap();
+
+        assert_eq!(class.implemented_interfaces(), vec!["IShape", "IPrintable"]);
+    }
+
+    #[test]
+    fn single_implemented_interface() {
+        let input = b"VERSION 1.0 CLASS
+BEGIN
+    MultiUse = -1  'True
+END
+Attribute VB_Name = \"Something\"
+Implements IShape
+";
+
+        let class = VB6ClassFile::parse("test.cls".to_owned(), &mut input.as_slice()).unwrap();
+
+        assert_eq!(class.implemented_interfaces(), vec!["IShape"]);
+    }
+
     #[test]
     fn class_file_invalid() {
         let input = b"VERSION 1.0 CLASS\r
@@ -510,4 +746,59 @@ Attribute VB_Exposed = False";
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn name_matches_file() {
+        let input = b"VERSION 1.0 CLASS\r
+BEGIN\r
+  MultiUse = -1  'True\r
+END\r
+Attribute VB_Name = \"Organism\"\r
+";
+
+        let result = VB6ClassFile::parse("Organism.cls".to_owned(), &mut input.as_slice()).unwrap();
+
+        assert!(result.name_matches_file("Organism.cls"));
+        assert!(result.name_matches_file("classes\\organism.CLS"));
+        assert!(!result.name_matches_file("Animal.cls"));
+    }
+
+    #[test]
+    fn interface_methods_grouped_by_interface() {
+        let input = b"VERSION 1.0 CLASS\r
+BEGIN\r
+  MultiUse = -1  'True\r
+END\r
+Attribute VB_Name = \"Widget\"\r
+Option Explicit\r
+Implements IFoo\r
+Implements IFooBar\r
+\r
+Private Sub IFoo_Bar()\r
+End Sub\r
+\r
+Private Function IFooBar_Baz() As Long\r
+End Function\r
+\r
+Private Property Get ifoo_Name() As String\r
+End Property\r
+\r
+Public Sub Helper()\r
+    IFoo_Bar\r
+End Sub\r
+";
+
+        let class = VB6ClassFile::parse("widget.cls".to_owned(), &mut input.as_slice()).unwrap();
+
+        assert_eq!(
+            class.interface_methods(),
+            vec![
+                (
+                    BStr::new("IFoo"),
+                    vec![BStr::new("IFoo_Bar"), BStr::new("ifoo_Name")]
+                ),
+                (BStr::new("IFooBar"), vec![BStr::new("IFooBar_Baz")]),
+            ]
+        );
+    }
 }
@@ -91,3 +91,39 @@ pub enum CompilationType {
     // -1, default
     PCode,
 }
+
+impl CompilationType {
+    /// Returns true if the project is compiled to p-code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Project;
+    ///
+    /// let project = VB6Project::parse("a.vbp", b"Type=Exe\r\nCompilationType=-1\r\n").unwrap();
+    ///
+    /// assert!(project.compilation_type.is_pcode());
+    /// assert!(!project.compilation_type.is_native());
+    /// ```
+    #[must_use]
+    pub fn is_pcode(&self) -> bool {
+        matches!(self, CompilationType::PCode)
+    }
+
+    /// Returns true if the project is compiled to native code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Project;
+    ///
+    /// let project = VB6Project::parse("a.vbp", b"Type=Exe\r\nCompilationType=0\r\n").unwrap();
+    ///
+    /// assert!(project.compilation_type.is_native());
+    /// assert!(!project.compilation_type.is_pcode());
+    /// ```
+    #[must_use]
+    pub fn is_native(&self) -> bool {
+        matches!(self, CompilationType::NativeCode { .. })
+    }
+}
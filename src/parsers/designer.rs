@@ -0,0 +1,170 @@
+use winnow::{
+    ascii::{space0, space1},
+    Parser,
+};
+
+use crate::{
+    errors::VB6Error,
+    language::{VB6Control, VB6Token},
+    parsers::{
+        form::{form_object_parse, root_control_parse},
+        header::{
+            attributes_parse, form_attributes_parse, version_parse, HeaderKind, VB6FileAttributes,
+            VB6FileFormatVersion,
+        },
+        VB6ObjectReference, VB6Stream,
+    },
+    vb6::{keyword_parse, vb6_parse},
+};
+
+/// The kinds of designer file which share the form file layout.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum DesignerKind {
+    Form,
+    UserControl,
+    PropertyPage,
+}
+
+impl DesignerKind {
+    /// The classes the root control of the file may have.
+    fn root_classes(self) -> &'static [&'static str] {
+        match self {
+            DesignerKind::Form => &["VB.Form", "VB.MDIForm"],
+            DesignerKind::UserControl => &["VB.UserControl"],
+            DesignerKind::PropertyPage => &["VB.PropertyPage"],
+        }
+    }
+
+    /// The name of the kind of file, as used in error messages.
+    fn file_kind(self) -> &'static str {
+        match self {
+            DesignerKind::Form => "form",
+            DesignerKind::UserControl => "user control",
+            DesignerKind::PropertyPage => "property page",
+        }
+    }
+}
+
+/// The parts of a designer file, before they are wrapped up in the type for
+/// the specific kind of file.
+pub(crate) struct VB6DesignerFile<'a> {
+    pub root: VB6Control<'a>,
+    pub objects: Vec<VB6ObjectReference<'a>>,
+    pub format_version: VB6FileFormatVersion,
+    pub attributes: VB6FileAttributes<'a>,
+    pub tokens: Vec<VB6Token<'a>>,
+}
+
+/// Parses a file laid out like a form file.
+///
+/// Forms, user controls, and property pages all have a version line, the
+/// object references, a single root control block, the attributes, and then
+/// the code. Only the class of the root control and the attribute defaults
+/// differ between them.
+///
+/// # Errors
+///
+/// An error will be returned if the input is not a valid designer file, or
+/// if the root control does not have one of the classes allowed for `kind`.
+pub(crate) fn designer_file_parse(
+    file_name: String,
+    input: &[u8],
+    kind: DesignerKind,
+) -> Result<VB6DesignerFile<'_>, VB6Error> {
+    let mut input = VB6Stream::new(file_name, input);
+
+    let format_version = match version_parse(HeaderKind::Form).parse_next(&mut input) {
+        Ok(version) => version,
+        Err(err) => return Err(input.error(err.into_inner().unwrap())),
+    };
+
+    let objects = match form_object_parse.parse_next(&mut input) {
+        Ok(objects) => objects,
+        Err(err) => return Err(input.error(err.into_inner().unwrap())),
+    };
+
+    match (space0, keyword_parse("BEGIN"), space1).parse_next(&mut input) {
+        Ok(_) => (),
+        Err(err) => return Err(input.error(err.into_inner().unwrap())),
+    };
+
+    let root = match root_control_parse(&mut input, kind.root_classes(), kind.file_kind()) {
+        Ok(root) => root,
+        Err(err) => return Err(input.error(err.into_inner().unwrap())),
+    };
+
+    let attributes = match kind {
+        DesignerKind::Form => form_attributes_parse.parse_next(&mut input),
+        DesignerKind::UserControl | DesignerKind::PropertyPage => {
+            attributes_parse.parse_next(&mut input)
+        }
+    };
+    let attributes = match attributes {
+        Ok(attributes) => attributes,
+        Err(err) => return Err(input.error(err.into_inner().unwrap())),
+    };
+
+    let tokens = match vb6_parse.parse_next(&mut input) {
+        Ok(tokens) => tokens,
+        Err(err) => return Err(input.error(err.into_inner().unwrap())),
+    };
+
+    Ok(VB6DesignerFile {
+        root,
+        objects,
+        format_version,
+        attributes,
+        tokens,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::errors::VB6ErrorKind;
+
+    #[test]
+    fn root_control_must_match_kind() {
+        let cases = [
+            (DesignerKind::Form, "VB.UserControl"),
+            (DesignerKind::UserControl, "VB.Form"),
+            (DesignerKind::PropertyPage, "VB.Form"),
+            (DesignerKind::PropertyPage, "VB.UserControl"),
+        ];
+
+        for (kind, class) in cases {
+            let input = format!(
+                "VERSION 5.00\r\nBegin {class} Example\r\n   ScaleWidth      =   4800\r\nEnd\r\nAttribute VB_Name = \"Example\"\r\n"
+            );
+
+            let Err(error) = designer_file_parse("example".to_owned(), input.as_bytes(), kind)
+            else {
+                panic!("{class} should not be accepted for {kind:?}");
+            };
+
+            assert_eq!(
+                error.kind,
+                VB6ErrorKind::UnexpectedRootControl {
+                    file_kind: kind.file_kind(),
+                    found: class.to_owned(),
+                }
+            );
+            assert_eq!(error.line_number, 2);
+        }
+    }
+
+    #[test]
+    fn mdi_form_is_a_form() {
+        let input = b"VERSION 5.00\r
+Begin VB.MDIForm mdiMain\r
+   Caption         =   \"Main\"\r
+End\r
+Attribute VB_Name = \"mdiMain\"\r
+";
+
+        let designer = designer_file_parse("mdiMain.frm".to_owned(), input, DesignerKind::Form);
+
+        assert!(designer.is_ok());
+    }
+}
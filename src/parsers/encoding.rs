@@ -0,0 +1,96 @@
+/// Decodes a VB6 source file saved as UTF-16.
+///
+/// VB6 itself saves source files in the system code page, but some tools
+/// re-save them as UTF-16 with a byte order mark. The parsers work on the
+/// original bytes, so such a file has to be decoded before it is parsed.
+///
+/// Both little endian (`FF FE`) and big endian (`FE FF`) byte order marks are
+/// recognized. The byte order mark is not part of the decoded text, and any
+/// invalid UTF-16 is replaced with the Unicode replacement character.
+///
+/// # Arguments
+///
+/// * `input` - The raw bytes of the file.
+///
+/// # Returns
+///
+/// The decoded text as UTF-8, or `None` if the input does not start with a
+/// UTF-16 byte order mark and should be parsed as is.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::decode_utf16;
+///
+/// let input = [0xFF, 0xFE, b'D', 0, b'i', 0, b'm', 0];
+///
+/// assert_eq!(decode_utf16(&input), Some("Dim".to_owned()));
+/// assert_eq!(decode_utf16(b"Dim"), None);
+/// ```
+#[must_use]
+pub fn decode_utf16(input: &[u8]) -> Option<String> {
+    let (little_endian, body) = match input {
+        [0xFF, 0xFE, body @ ..] => (true, body),
+        [0xFE, 0xFF, body @ ..] => (false, body),
+        _ => return None,
+    };
+
+    let units = body
+        .chunks(2)
+        .map(|pair| match pair {
+            [first, second] if little_endian => u16::from_le_bytes([*first, *second]),
+            [first, second] => u16::from_be_bytes([*first, *second]),
+            // An odd byte at the end cannot be a whole code unit.
+            _ => char::REPLACEMENT_CHARACTER as u16,
+        })
+        .collect::<Vec<_>>();
+
+    Some(String::from_utf16_lossy(&units))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::VB6FormFile;
+
+    fn encode(text: &str, to_bytes: fn(u16) -> [u8; 2], bom: [u8; 2]) -> Vec<u8> {
+        let mut bytes = bom.to_vec();
+        bytes.extend(text.encode_utf16().flat_map(to_bytes));
+        bytes
+    }
+
+    #[test]
+    fn utf16_form() {
+        let source = "VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        for input in [
+            encode(source, u16::to_le_bytes, [0xFF, 0xFE]),
+            encode(source, u16::to_be_bytes, [0xFE, 0xFF]),
+        ] {
+            let decoded = decode_utf16(&input).unwrap();
+            assert_eq!(decoded, source);
+
+            let form = VB6FormFile::parse("utf16.frm".to_owned(), decoded.as_bytes()).unwrap();
+            assert_eq!(form.attributes.name, "frmMain");
+        }
+    }
+
+    #[test]
+    fn no_byte_order_mark() {
+        assert_eq!(decode_utf16(b"VERSION 5.00\r\n"), None);
+        assert_eq!(decode_utf16(b""), None);
+    }
+
+    #[test]
+    fn odd_trailing_byte() {
+        assert_eq!(
+            decode_utf16(&[0xFF, 0xFE, b'A', 0, b'B']),
+            Some("A\u{FFFD}".to_owned())
+        );
+    }
+}
@@ -7,11 +7,11 @@ use either::Either;
 use num_enum::TryFromPrimitive;
 use serde::Serialize;
 use uuid::Uuid;
-use winnow::error::ParserError;
 use winnow::{
     ascii::{line_ending, space0, space1},
     combinator::{alt, opt},
     error::ErrMode,
+    stream::Stream,
     token::{literal, take_till, take_until},
     Parser,
 };
@@ -28,13 +28,11 @@ use crate::{
         TimerProperties, VB6Color, VB6Control, VB6ControlKind, VB6MenuControl, VB6Token,
     },
     parsers::{
-        header::{
-            attributes_parse, key_resource_offset_line_parse, version_parse, HeaderKind,
-            VB6FileAttributes, VB6FileFormatVersion,
-        },
+        designer::{designer_file_parse, DesignerKind},
+        header::{key_resource_offset_line_parse, VB6FileAttributes, VB6FileFormatVersion},
         VB6ObjectReference, VB6Stream,
     },
-    vb6::{keyword_parse, line_comment_parse, vb6_parse, VB6Result},
+    vb6::{keyword_parse, line_comment_parse, VB6Result},
 };
 
 use super::{header::object_parse, vb6::string_parse};
@@ -53,6 +51,8 @@ pub struct VB6FormFile<'a> {
 struct VB6FullyQualifiedName<'a> {
     pub namespace: &'a BStr,
     pub kind: &'a BStr,
+    /// The namespace and kind together, as written, e.g. `MSComctlLib.ListView`.
+    pub class: &'a BStr,
     pub name: &'a BStr,
 }
 
@@ -144,49 +144,102 @@ impl<'a> VB6FormFile<'a> {
     /// assert!(result.is_ok());
     /// ```
     pub fn parse(file_name: String, input: &'a [u8]) -> Result<Self, VB6Error> {
-        let mut input = VB6Stream::new(file_name, input);
-
-        let format_version = match version_parse(HeaderKind::Form).parse_next(&mut input) {
-            Ok(version) => version,
-            Err(err) => return Err(input.error(err.into_inner().unwrap())),
-        };
+        let designer = designer_file_parse(file_name, input, DesignerKind::Form)?;
 
-        let objects = match form_object_parse.parse_next(&mut input) {
-            Ok(objects) => objects,
-            Err(err) => return Err(input.error(err.into_inner().unwrap())),
-        };
+        Ok(VB6FormFile {
+            form: designer.root,
+            objects: designer.objects,
+            format_version: designer.format_version,
+            attributes: designer.attributes,
+            tokens: designer.tokens,
+        })
+    }
 
-        match (space0, keyword_parse("BEGIN"), space1).parse_next(&mut input) {
-            Ok(_) => (),
-            Err(err) => return Err(input.error(err.into_inner().unwrap())),
-        };
+    /// Finds a control or menu anywhere in the form by name.
+    ///
+    /// The search is depth first and ignores letter case, like VB6 itself.
+    /// Control names are unique within a form, so the first match is the
+    /// only one. The form itself can also be found by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the control or menu to look for.
+    ///
+    /// # Returns
+    ///
+    /// The matching control on the left or menu on the right, or `None` if
+    /// nothing in the form has that name.
+    #[must_use]
+    pub fn control_by_name(
+        &self,
+        name: &str,
+    ) -> Option<Either<&VB6Control<'a>, &VB6MenuControl<'a>>> {
+        find_control(&self.form, name.as_bytes())
+    }
 
-        let form = match block_parse.parse_next(&mut input) {
-            Ok(form) => form,
-            Err(err) => return Err(input.error(err.into_inner().unwrap())),
-        };
+    /// Checks the form `VB_Name` against the name of the file it came from.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The file name or path of the form file.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file stem matches the `VB_Name` attribute, ignoring case.
+    #[must_use]
+    pub fn name_matches_file(&self, file_name: &str) -> bool {
+        self.attributes.name_matches_file(file_name)
+    }
+}
 
-        let attributes = match attributes_parse.parse_next(&mut input) {
-            Ok(attributes) => attributes,
-            Err(err) => return Err(input.error(err.into_inner().unwrap())),
-        };
+fn find_control<'c, 'a>(
+    control: &'c VB6Control<'a>,
+    name: &[u8],
+) -> Option<Either<&'c VB6Control<'a>, &'c VB6MenuControl<'a>>> {
+    if control.name.eq_ignore_ascii_case(name) {
+        return Some(Either::Left(control));
+    }
 
-        let tokens = match vb6_parse.parse_next(&mut input) {
-            Ok(tokens) => tokens,
-            Err(err) => return Err(input.error(err.into_inner().unwrap())),
-        };
+    let (controls, menus) = match &control.kind {
+        VB6ControlKind::Form {
+            controls, menus, ..
+        }
+        | VB6ControlKind::MDIForm {
+            controls, menus, ..
+        }
+        | VB6ControlKind::UserControl {
+            controls, menus, ..
+        } => (controls.as_slice(), menus.as_slice()),
+        VB6ControlKind::Frame { controls, .. } | VB6ControlKind::PropertyPage { controls, .. } => {
+            (controls.as_slice(), [].as_slice())
+        }
+        _ => return None,
+    };
 
-        Ok(VB6FormFile {
-            form,
-            objects,
-            format_version,
-            attributes,
-            tokens,
+    controls
+        .iter()
+        .find_map(|control| find_control(control, name))
+        .or_else(|| {
+            menus
+                .iter()
+                .find_map(|menu| find_menu(menu, name))
+                .map(Either::Right)
         })
+}
+
+fn find_menu<'c, 'a>(menu: &'c VB6MenuControl<'a>, name: &[u8]) -> Option<&'c VB6MenuControl<'a>> {
+    if menu.name.eq_ignore_ascii_case(name) {
+        return Some(menu);
     }
+
+    menu.sub_menus
+        .iter()
+        .find_map(|sub_menu| find_menu(sub_menu, name))
 }
 
-fn form_object_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<Vec<VB6ObjectReference<'a>>> {
+pub(crate) fn form_object_parse<'a>(
+    input: &mut VB6Stream<'a>,
+) -> VB6Result<Vec<VB6ObjectReference<'a>>> {
     let mut objects = vec![];
 
     loop {
@@ -207,19 +260,90 @@ fn form_object_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<Vec<VB6ObjectRe
     Ok(objects)
 }
 
-fn block_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6Control<'a>> {
+/// Parses the root control block of a designer file.
+///
+/// The class of the root control is checked against `allowed` before the
+/// block is parsed, so a form is not accepted as a user control and the
+/// like.
+///
+/// # Arguments
+///
+/// * `input` - The stream to parse, just after the `BEGIN` keyword.
+/// * `allowed` - The classes the root control may have, such as `VB.Form`.
+/// * `file_kind` - The kind of file being parsed, used in the error.
+///
+/// # Errors
+///
+/// Returns `UnexpectedRootControl` if the root control has any other class,
+/// or any error from parsing the block itself.
+pub(crate) fn root_control_parse<'a>(
+    input: &mut VB6Stream<'a>,
+    allowed: &[&str],
+    file_kind: &'static str,
+) -> VB6Result<VB6Control<'a>> {
+    let checkpoint = input.checkpoint();
+    let fully_qualified_name = property_parse.parse_next(input)?;
+    input.reset(&checkpoint);
+
+    if !allowed.iter().any(|class| {
+        fully_qualified_name
+            .class
+            .eq_ignore_ascii_case(class.as_bytes())
+    }) {
+        return Err(ErrMode::Cut(VB6ErrorKind::UnexpectedRootControl {
+            file_kind,
+            found: fully_qualified_name.class.to_string(),
+        }));
+    }
+
+    // the root control has no parent for an unmatched `End` to close.
+    block_parse(input, 0, None)
+}
+
+/// Parses a control block, just after its `Begin` keyword, up to and
+/// including its `End`.
+///
+/// Designer files written by hand or by other tools don't always use VB6's
+/// casing, so `Begin`, `End`, `BeginProperty` and `EndProperty` are matched
+/// without regard to case.
+///
+/// `indent` is the width of the whitespace before the `Begin` keyword, and
+/// `parent_indent` that of the parent control's `Begin`. VB6 indents each
+/// control inside its parent, so an `End` indented no further than the
+/// parent closes the parent, and this control is missing its own `End`.
+/// An unterminated control is reported at its `Begin` line.
+pub(crate) fn block_parse<'a>(
+    input: &mut VB6Stream<'a>,
+    indent: usize,
+    parent_indent: Option<usize>,
+) -> VB6Result<VB6Control<'a>> {
+    let begin = input.checkpoint();
     let fully_qualified_name = property_parse.parse_next(input)?;
 
+    let unterminated = |input: &mut VB6Stream<'a>| {
+        input.reset(&begin);
+
+        Err(ErrMode::Cut(VB6ErrorKind::UnterminatedControl {
+            name: fully_qualified_name.name.to_string(),
+        }))
+    };
+
     let mut controls = vec![];
     let mut menus = vec![];
     let mut property_groups = vec![];
     let mut properties = HashMap::new();
 
     while !input.is_empty() {
-        if (space0, keyword_parse("END"), space0, line_ending)
+        let line_indent = space0.parse_next(input)?.len();
+
+        if (keyword_parse("END"), space0, line_ending)
             .parse_next(input)
             .is_ok()
         {
+            if parent_indent.is_some_and(|parent_indent| line_indent <= parent_indent) {
+                return unterminated(input);
+            }
+
             match build_control(
                 fully_qualified_name,
                 controls,
@@ -232,11 +356,8 @@ fn block_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6Control<'a>> {
             };
         }
 
-        if (space0, keyword_parse("BEGIN"), space1)
-            .parse_next(input)
-            .is_ok()
-        {
-            let control = block_parse.parse_next(input)?;
+        if (keyword_parse("BEGIN"), space1).parse_next(input).is_ok() {
+            let control = block_parse(input, line_indent, Some(indent))?;
             if control.kind.is_menu() {
                 menus.push(control);
             } else {
@@ -246,6 +367,15 @@ fn block_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6Control<'a>> {
             continue;
         }
 
+        // The designer block is always followed by the attributes, so
+        // reaching them means the control was never closed.
+        if (space0, keyword_parse("Attribute"))
+            .parse_next(input)
+            .is_ok()
+        {
+            return unterminated(input);
+        }
+
         if let Ok(property_group) = property_group_parse.parse_next(input) {
             property_groups.push(property_group);
             continue;
@@ -282,7 +412,7 @@ fn block_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6Control<'a>> {
         line_ending.parse_next(input)?;
     }
 
-    Err(ParserError::assert(input, "Unknown control kind"))
+    unterminated(input)
 }
 
 fn property_group_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6PropertyGroup<'a>> {
@@ -372,6 +502,8 @@ fn property_group_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6PropertyG
 }
 
 fn property_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FullyQualifiedName<'a>> {
+    let class_start = input.index;
+
     let Ok(namespace) = take_until::<_, _, VB6Error>(0.., ".").parse_next(input) else {
         return Err(ErrMode::Cut(VB6ErrorKind::NoNamespaceAfterBegin));
     };
@@ -384,6 +516,9 @@ fn property_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FullyQualifiedN
         return Err(ErrMode::Cut(VB6ErrorKind::NoUserControlNameAfterDot));
     };
 
+    let stream: &'a BStr = input.stream;
+    let class = stream[class_start..input.index].as_bstr();
+
     if space1::<_, VB6Error>.parse_next(input).is_err() {
         return Err(ErrMode::Cut(VB6ErrorKind::NoSpaceAfterControlKind));
     }
@@ -402,10 +537,33 @@ fn property_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FullyQualifiedN
     Ok(VB6FullyQualifiedName {
         namespace,
         kind,
+        class,
         name,
     })
 }
 
+/// Converts the menu controls found in a block into menu entries.
+///
+/// Anything which is not a menu is dropped.
+fn convert_menus(menus: Vec<VB6Control<'_>>) -> Vec<VB6MenuControl<'_>> {
+    menus
+        .into_iter()
+        .filter_map(|menu| match menu.kind {
+            VB6ControlKind::Menu {
+                properties,
+                sub_menus,
+            } => Some(VB6MenuControl {
+                name: menu.name,
+                tag: menu.tag,
+                index: menu.index,
+                properties,
+                sub_menus,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
 fn build_control<'a>(
     fully_qualified_name: VB6FullyQualifiedName<'a>,
     controls: Vec<VB6Control<'a>>,
@@ -420,12 +578,18 @@ fn build_control<'a>(
         BStr::new("")
     };
 
+    // only the members of a control array have an index.
+    let index = properties
+        .get(BStr::new("Index"))
+        .and_then(|index| index.to_str().ok()?.trim().parse::<i32>().ok());
+
     if fully_qualified_name.namespace != "VB" {
         let custom_control = VB6Control {
             name: fully_qualified_name.name,
             tag,
-            index: 0,
+            index,
             kind: VB6ControlKind::Custom {
+                class: fully_qualified_name.class,
                 properties,
                 property_groups,
             },
@@ -438,81 +602,38 @@ fn build_control<'a>(
         b"Form" => {
             let form_properties = FormProperties::construct_control(properties, property_groups)?;
 
-            let mut converted_menus = vec![];
-
-            for menu in menus {
-                if let VB6ControlKind::Menu {
-                    properties: menu_properties,
-                    sub_menus,
-                } = menu.kind
-                {
-                    converted_menus.push(VB6MenuControl {
-                        name: menu.name,
-                        tag: menu.tag,
-                        index: menu.index,
-                        properties: menu_properties,
-                        sub_menus,
-                    });
-                }
-            }
-
             VB6ControlKind::Form {
                 controls,
                 properties: form_properties,
-                menus: converted_menus,
+                menus: convert_menus(menus),
             }
         }
         b"MDIForm" => {
             let mdi_form_properties =
                 MDIFormProperties::construct_control(properties, property_groups)?;
 
-            let mut converted_menus = vec![];
-
-            for menu in menus {
-                if let VB6ControlKind::Menu {
-                    properties: menu_properties,
-                    sub_menus,
-                } = menu.kind
-                {
-                    converted_menus.push(VB6MenuControl {
-                        name: menu.name,
-                        tag: menu.tag,
-                        index: menu.index,
-                        properties: menu_properties,
-                        sub_menus,
-                    });
-                }
-            }
-
             VB6ControlKind::MDIForm {
                 controls,
                 properties: mdi_form_properties,
-                menus: converted_menus,
+                menus: convert_menus(menus),
             }
         }
+        b"UserControl" => VB6ControlKind::UserControl {
+            properties,
+            property_groups,
+            controls,
+            menus: convert_menus(menus),
+        },
+        b"PropertyPage" => VB6ControlKind::PropertyPage {
+            properties,
+            property_groups,
+            controls,
+        },
         b"Menu" => {
             let menu_properties = MenuProperties::build_control(&properties)?;
-            let mut converted_menus = vec![];
-
-            for menu in menus {
-                if let VB6ControlKind::Menu {
-                    properties: menu_properties,
-                    sub_menus,
-                } = menu.kind
-                {
-                    converted_menus.push(VB6MenuControl {
-                        name: menu.name,
-                        tag: menu.tag,
-                        index: menu.index,
-                        properties: menu_properties,
-                        sub_menus,
-                    });
-                }
-            }
-
             VB6ControlKind::Menu {
                 properties: menu_properties,
-                sub_menus: converted_menus,
+                sub_menus: convert_menus(menus),
             }
         }
         b"Frame" => {
@@ -663,7 +784,7 @@ fn build_control<'a>(
     let parent_control = VB6Control {
         name: fully_qualified_name.name,
         tag,
-        index: 0,
+        index,
         kind,
     };
 
@@ -925,10 +1046,12 @@ Attribute VB_Name = \"Form_Main\"\r
             assert!(matches!(controls[0].kind, VB6ControlKind::Custom { .. }));
 
             if let VB6ControlKind::Custom {
+                class,
                 properties,
                 property_groups,
             } = &controls[0].kind
             {
+                assert_eq!(*class, "MSComctlLib.ImageList");
                 assert_eq!(properties.len(), 9);
                 assert_eq!(property_groups.len(), 1);
 
@@ -1081,7 +1204,7 @@ Attribute VB_Name = \"FormMainMode\"\r
 
         assert_eq!(result.form.name, "frmExampleForm");
         assert_eq!(result.form.tag, "");
-        assert_eq!(result.form.index, 0);
+        assert_eq!(result.form.index, None);
 
         if let VB6ControlKind::Form {
             controls,
@@ -1098,7 +1221,7 @@ Attribute VB_Name = \"FormMainMode\"\r
                 &vec![VB6MenuControl {
                     name: BStr::new("mnuFile"),
                     tag: BStr::new(""),
-                    index: 0,
+                    index: None,
                     properties: MenuProperties {
                         caption: BStr::new("&File"),
                         ..Default::default()
@@ -1106,7 +1229,7 @@ Attribute VB_Name = \"FormMainMode\"\r
                     sub_menus: vec![VB6MenuControl {
                         name: BStr::new("mnuOpenImage"),
                         tag: BStr::new(""),
-                        index: 0,
+                        index: None,
                         properties: MenuProperties {
                             caption: BStr::new("&Open image"),
                             ..Default::default()
@@ -1119,4 +1242,433 @@ Attribute VB_Name = \"FormMainMode\"\r
             panic!("Expected form kind");
         }
     }
+
+    #[test]
+    fn control_size_in_pixels() {
+        use crate::language::{ScaleMode, DEFAULT_DPI};
+
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmExampleForm\r
+   Caption         =   \"example form\"\r
+   ClientHeight    =   6210\r
+   ClientLeft      =   60\r
+   ClientTop       =   645\r
+   ClientWidth     =   9900\r
+   Height          =   1440\r
+   Width           =   2880\r
+   ScaleHeight     =   414\r
+   ScaleMode       =   3  'Pixel\r
+   ScaleWidth      =   660\r
+   Begin VB.CommandButton cmdOk\r
+      Caption         =   \"OK\"\r
+      Height          =   25\r
+      Left            =   8\r
+      Top             =   16\r
+      Width           =   75\r
+   End\r
+End\r
+Attribute VB_Name = \"frmExampleForm\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        // A form's own size is always in twips.
+        assert_eq!(
+            result.form.height_pixels(&ScaleMode::Twip, DEFAULT_DPI),
+            Some(96)
+        );
+        assert_eq!(
+            result.form.width_pixels(&ScaleMode::Twip, DEFAULT_DPI),
+            Some(192)
+        );
+        assert_eq!(result.form.width_pixels(&ScaleMode::Twip, 120), Some(240));
+
+        let scale_mode = result.form.scale_mode().unwrap();
+        assert_eq!(scale_mode, &ScaleMode::Pixel);
+
+        let VB6ControlKind::Form { controls, .. } = &result.form.kind else {
+            panic!("Expected form kind");
+        };
+
+        let button = &controls[0];
+        assert_eq!(button.left_pixels(scale_mode, DEFAULT_DPI), Some(8));
+        assert_eq!(button.top_pixels(scale_mode, DEFAULT_DPI), Some(16));
+        assert_eq!(button.width_pixels(scale_mode, DEFAULT_DPI), Some(75));
+        assert_eq!(button.height_pixels(scale_mode, DEFAULT_DPI), Some(25));
+        assert_eq!(button.scale_mode(), None);
+    }
+
+    #[test]
+    fn large_form_borrows_from_source() {
+        let mut input = String::from("VERSION 5.00\r\nBegin VB.Form frmLarge\r\n");
+        for i in 0..15_000 {
+            input.push_str(&format!(
+                "   Begin VB.CommandButton cmdButton{i}\r
+      Caption         =   \"Button number {i}\"\r
+      Height          =   375\r
+      Left            =   120\r
+      Top             =   120\r
+      Width           =   1215\r
+   End\r
+"
+            ));
+        }
+        input.push_str("End\r\nAttribute VB_Name = \"frmLarge\"\r\n");
+        assert!(input.len() > 2 * 1024 * 1024);
+
+        let result = VB6FormFile::parse("large.frm".to_owned(), input.as_bytes()).unwrap();
+
+        let source = input.as_bytes().as_ptr_range();
+        let borrowed = |value: &BStr| {
+            let range = value.as_ptr_range();
+            source.start <= range.start && range.end <= source.end
+        };
+
+        let VB6ControlKind::Form { controls, .. } = &result.form.kind else {
+            panic!("Expected form kind");
+        };
+
+        assert_eq!(controls.len(), 15_000);
+        assert!(borrowed(result.form.name));
+        for control in controls {
+            assert!(borrowed(control.name));
+            let VB6ControlKind::CommandButton { properties } = &control.kind else {
+                panic!("Expected command button kind");
+            };
+            assert!(borrowed(properties.caption));
+        }
+    }
+
+    #[test]
+    fn control_by_name() {
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmExampleForm\r
+   Caption         =   \"example form\"\r
+   Begin VB.Frame fraOptions\r
+      Caption         =   \"Options\"\r
+      Begin VB.CheckBox chkEnabled\r
+         Caption         =   \"Enabled\"\r
+      End\r
+   End\r
+   Begin VB.Menu mnuFile\r
+      Caption         =   \"&File\"\r
+      Begin VB.Menu mnuOpen\r
+         Caption         =   \"&Open\"\r
+         Begin VB.Menu mnuOpenImage\r
+            Caption         =   \"&Open image\"\r
+         End\r
+      End\r
+   End\r
+End\r
+Attribute VB_Name = \"frmExampleForm\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        let Some(Either::Right(menu)) = result.control_by_name("MNUOPENIMAGE") else {
+            panic!("Expected to find the menu");
+        };
+        assert_eq!(menu.name, "mnuOpenImage");
+        assert_eq!(menu.properties.caption, "&Open image");
+
+        let Some(Either::Left(check_box)) = result.control_by_name("chkEnabled") else {
+            panic!("Expected to find the check box");
+        };
+        assert_eq!(check_box.name, "chkEnabled");
+
+        let Some(Either::Left(form)) = result.control_by_name("frmExampleForm") else {
+            panic!("Expected to find the form");
+        };
+        assert_eq!(form.name, "frmExampleForm");
+
+        assert_eq!(result.control_by_name("cmdMissing"), None);
+    }
+
+    #[test]
+    fn third_party_control_class() {
+        let input = b"VERSION 5.00\r
+Object = \"{831FDD16-0C5C-11D2-A9FC-0000F8754DA1}#2.0#0\"; \"MSCOMCTL.OCX\"\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   Begin VB.Frame fraFiles\r
+      Caption         =   \"Files\"\r
+      Begin MSComctlLib.ListView lvwFiles\r
+         Height          =   2535\r
+         Left            =   120\r
+         Top             =   240\r
+         Width           =   4215\r
+         _Version        =   393217\r
+      End\r
+   End\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        assert!(result.form.is_vb_intrinsic());
+
+        let Some(Either::Left(frame)) = result.control_by_name("fraFiles") else {
+            panic!("Expected to find the frame");
+        };
+        assert!(frame.is_vb_intrinsic());
+
+        let Some(Either::Left(list_view)) = result.control_by_name("lvwFiles") else {
+            panic!("Expected to find the list view");
+        };
+        assert!(!list_view.is_vb_intrinsic());
+
+        let VB6ControlKind::Custom { class, .. } = &list_view.kind else {
+            panic!("Expected custom kind");
+        };
+        assert_eq!(*class, "MSComctlLib.ListView");
+    }
+
+    #[test]
+    fn form_attributes() {
+        use crate::parsers::header::{
+            Creatable, Exposed, NameSpace, PreDeclaredID, TemplateDerived,
+        };
+
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+Attribute VB_PredeclaredId = True\r
+Attribute VB_TemplateDerived = True\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        assert_eq!(result.attributes.name, "frmMain");
+        assert_eq!(result.attributes.pre_declared_id, PreDeclaredID::True);
+        assert_eq!(result.attributes.template_derived, TemplateDerived::True);
+        // Missing attributes take the values VB6 gives a form.
+        assert_eq!(result.attributes.global_name_space, NameSpace::Local);
+        assert_eq!(result.attributes.creatable, Creatable::False);
+        assert_eq!(result.attributes.exposed, Exposed::False);
+
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+Attribute VB_PredeclaredId = False\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        assert_eq!(result.attributes.pre_declared_id, PreDeclaredID::False);
+        assert_eq!(result.attributes.template_derived, TemplateDerived::False);
+    }
+
+    #[test]
+    fn mixed_case_block_keywords() {
+        let input = b"VERSION 5.00\r
+begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   beginproperty Font\r
+      Name            =   \"Arial\"\r
+   ENDPROPERTY\r
+   BEGIN VB.CommandButton cmdOk\r
+      Caption         =   \"OK\"\r
+   end\r
+END\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref());
+
+        let form = result.unwrap();
+        assert_eq!(form.form.name, "frmMain");
+
+        let VB6ControlKind::Form {
+            controls,
+            properties,
+            ..
+        } = &form.form.kind
+        else {
+            panic!("Expected a form.");
+        };
+        assert_eq!(controls.len(), 1);
+        assert_eq!(controls[0].name, "cmdOk");
+        assert_eq!(properties.caption, "Main");
+    }
+
+    #[test]
+    fn unterminated_control() {
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   Begin VB.CommandButton cmdOk\r
+      Caption         =   \"OK\"\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref());
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.kind,
+            VB6ErrorKind::UnterminatedControl {
+                name: "cmdOk".to_owned()
+            }
+        );
+        assert_eq!(err.line_number, 4);
+
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   Begin VB.CommandButton cmdOk\r
+      Caption         =   \"OK\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref());
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.kind,
+            VB6ErrorKind::UnterminatedControl {
+                name: "cmdOk".to_owned()
+            }
+        );
+        assert_eq!(err.line_number, 4);
+
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref());
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.kind,
+            VB6ErrorKind::UnterminatedControl {
+                name: "frmMain".to_owned()
+            }
+        );
+        assert_eq!(err.line_number, 2);
+    }
+
+    #[test]
+    fn property_values_are_byte_exact() {
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   Begin VB.CommandButton cmdOk\r
+      Caption         =   \"OK\"\r
+      Tag             =   \"  \"\"quoted\"\"  \"\r
+      ToolTipText     =   \"Press \"\"OK\"\"   \"   ' comment\r
+   End\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        let Some(Either::Left(button)) = result.control_by_name("cmdOk") else {
+            panic!("Expected to find the button");
+        };
+
+        // Only the surrounding quotes are removed. Escaped quotes and any
+        // spaces inside the quotes are kept as they are in the file.
+        assert_eq!(button.tag, "  \"\"quoted\"\"  ");
+
+        match &button.kind {
+            VB6ControlKind::CommandButton { properties } => {
+                assert_eq!(properties.tool_tip_text, "Press \"\"OK\"\"   ");
+            }
+            _ => panic!("Expected a command button"),
+        }
+    }
+
+    #[test]
+    fn event_handler_name() {
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   Begin VB.CommandButton Command1\r
+      Caption         =   \"OK\"\r
+   End\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        let Some(Either::Left(button)) = result.control_by_name("Command1") else {
+            panic!("Expected to find the button");
+        };
+
+        assert_eq!(button.event_handler_name("Click"), "Command1_Click");
+        assert!(!button.is_array_member());
+        assert_eq!(result.form.event_handler_name("Load"), "Form_Load");
+    }
+
+    #[test]
+    fn control_array_members() {
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+   Begin VB.CommandButton cmdAction\r
+      Caption         =   \"Two\"\r
+      Index           =   1\r
+   End\r
+   Begin VB.CommandButton cmdAction\r
+      Caption         =   \"One\"\r
+      Index           =   0\r
+   End\r
+   Begin VB.Menu mnuRecent\r
+      Caption         =   \"Recent\"\r
+      Index           =   0\r
+   End\r
+   Begin VB.Menu mnuFile\r
+      Caption         =   \"File\"\r
+   End\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("form_parse.frm".to_owned(), input.as_ref()).unwrap();
+
+        let VB6ControlKind::Form {
+            controls, menus, ..
+        } = &result.form.kind
+        else {
+            panic!("Expected a form.");
+        };
+
+        assert_eq!(controls[0].index, Some(1));
+        assert_eq!(controls[1].index, Some(0));
+        assert!(controls[1].is_array_member());
+        assert_eq!(controls[1].event_handler_name("Click"), "cmdAction_Click");
+
+        assert_eq!(menus[0].index, Some(0));
+        assert!(menus[0].is_array_member());
+        assert_eq!(menus[0].event_handler_name("Click"), "mnuRecent_Click");
+        assert!(!menus[1].is_array_member());
+        assert_eq!(menus[1].event_handler_name("Click"), "mnuFile_Click");
+    }
+
+    #[test]
+    fn name_matches_file() {
+        let input = b"VERSION 5.00\r
+Begin VB.Form frmMain\r
+   Caption         =   \"Main\"\r
+End\r
+Attribute VB_Name = \"frmMain\"\r
+";
+
+        let result = VB6FormFile::parse("frmMain.frm".to_owned(), input.as_ref()).unwrap();
+
+        assert!(result.name_matches_file("frmMain.frm"));
+        assert!(result.name_matches_file("C:\\Project\\FRMMAIN.FRM"));
+        assert!(result.name_matches_file("src/forms/frmmain.frm"));
+        assert!(!result.name_matches_file("frmMain2.frm"));
+        assert!(!result.name_matches_file("frmMain.frm.bak"));
+    }
 }
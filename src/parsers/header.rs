@@ -7,7 +7,7 @@ use winnow::{
     ascii::{digit1, line_ending, space0, space1},
     combinator::{alt, eof, opt},
     error::ErrMode,
-    stream::Stream,
+    stream::{Compare, CompareResult, Stream},
     token::{literal, take_till, take_until, take_while},
     Parser,
 };
@@ -117,6 +117,16 @@ pub enum Exposed {
     True,
 }
 
+/// Used to determine if a form was created from a form template.
+///
+/// The `VB_TemplateDerived` attribute is not normally visible in the code
+/// editor region. It only appears in form files.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub enum TemplateDerived {
+    False,
+    True,
+}
+
 /// Represents the attributes of a VB6 file file.
 /// The attributes contain the name, global name space, creatable, pre-declared id, and exposed.
 ///
@@ -129,6 +139,7 @@ pub struct VB6FileAttributes<'a> {
     pub creatable: Creatable,                 // (True/False) Attribute VB_Creatable = True
     pub pre_declared_id: PreDeclaredID,       // (True/False) Attribute VB_PredeclaredId = False
     pub exposed: Exposed,                     // (True/False) Attribute VB_Exposed = False
+    pub template_derived: TemplateDerived,    // (True/False) Attribute VB_TemplateDerived = False
     pub description: Option<&'a BStr>,        // Attribute VB_Description = "Description"
     pub ext_key: HashMap<&'a BStr, &'a BStr>, // Additional attributes
 }
@@ -141,17 +152,60 @@ impl Default for VB6FileAttributes<'_> {
             creatable: Creatable::True,
             pre_declared_id: PreDeclaredID::False,
             exposed: Exposed::False,
+            template_derived: TemplateDerived::False,
             description: None,
             ext_key: HashMap::new(),
         }
     }
 }
+
+impl VB6FileAttributes<'_> {
+    /// The attributes VB6 gives a form.
+    ///
+    /// Unlike a class, a form is not creatable from outside and has a
+    /// pre-declared id, so it can be used through its name without `New`.
+    #[must_use]
+    pub fn form_default() -> Self {
+        VB6FileAttributes {
+            creatable: Creatable::False,
+            pre_declared_id: PreDeclaredID::True,
+            ..Default::default()
+        }
+    }
+
+    /// Checks the `VB_Name` attribute against the stem of a file name.
+    ///
+    /// VB6 expects a module to be saved in a file named after it, but nothing
+    /// stops the two from drifting apart when a file is renamed outside of
+    /// the IDE. The comparison is case-insensitive, just like VB6 names, and
+    /// both `/` and `\` are treated as path separators.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The file name or path the attributes were read from.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file stem matches the `VB_Name` attribute.
+    #[must_use]
+    pub fn name_matches_file(&self, file_name: &str) -> bool {
+        let file_name = file_name.rsplit(['/', '\\']).next().unwrap_or_default();
+        let stem = match file_name.rfind('.') {
+            Some(0) | None => file_name,
+            Some(dot) => &file_name[..dot],
+        };
+
+        self.name.eq_ignore_ascii_case(stem.as_bytes())
+    }
+}
+
 enum Attributes {
     Name,
     GlobalNameSpace,
     Creatable,
     PredeclaredId,
     Exposed,
+    TemplateDerived,
     Description,
     ExtKey,
 }
@@ -219,9 +273,27 @@ fn compiled_object_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6ObjectRe
 
     "}#".parse_next(input)?;
 
+    if input.compare("#") == CompareResult::Ok(1) {
+        return Err(ErrMode::Cut(VB6ErrorKind::ObjectVersionMissing));
+    }
+
+    let version_start = input.checkpoint();
     // still not sure what this element or the next represents.
     let version = take_until(1.., "#").parse_next(input)?;
 
+    // Type library versions are written in hex, so a minor version of 10
+    // shows up as "1.a". Leave the stream on the first bad character so the
+    // error points at it.
+    if let Some(position) = version
+        .iter()
+        .position(|c| !(c.is_ascii_hexdigit() || *c == b'.'))
+    {
+        input.reset(&version_start);
+        input.next_slice(position);
+
+        return Err(ErrMode::Cut(VB6ErrorKind::ObjectVersionInvalid));
+    }
+
     "#".parse_next(input)?;
 
     // we have to take until the next semi-colon or the next semi-colon wrapped in double-qoutes since it could be qouted or not.
@@ -229,8 +301,9 @@ fn compiled_object_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6ObjectRe
 
     opt("\"").parse_next(input)?;
     // the file name is preceded by a semi-colon then a space. not sure why the
-    // space is there, but it is. this strips it and the semi-colon out.
-    "; ".parse_next(input)?;
+    // space is there, but it is. some files leave the space out, so this
+    // strips the semi-colon and any spaces that follow it.
+    (";", space0).parse_next(input)?;
 
     // the filename may or may not be wrapped in double-qoutes.
     opt("\"").parse_next(input)?;
@@ -298,15 +371,34 @@ pub fn object_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6ObjectReferen
 }
 
 pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttributes<'a>> {
+    attributes_with_defaults_parse(input, VB6FileAttributes::default())
+}
+
+/// Parses the attributes of a form file.
+///
+/// Attributes which are missing take the values VB6 gives a form, see
+/// [`VB6FileAttributes::form_default`].
+pub fn form_attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttributes<'a>> {
+    attributes_with_defaults_parse(input, VB6FileAttributes::form_default())
+}
+
+fn attributes_with_defaults_parse<'a>(
+    input: &mut VB6Stream<'a>,
+    defaults: VB6FileAttributes<'a>,
+) -> VB6Result<VB6FileAttributes<'a>> {
     let _ = space0::<_, VB6ErrorKind>.parse_next(input);
 
+    let VB6FileAttributes {
+        mut global_name_space,
+        mut creatable,
+        mut pre_declared_id,
+        mut exposed,
+        mut template_derived,
+        mut description,
+        mut ext_key,
+        ..
+    } = defaults;
     let mut name = None;
-    let mut global_name_space = NameSpace::Local;
-    let mut creatable = Creatable::True;
-    let mut pre_declared_id = PreDeclaredID::False;
-    let mut exposed = Exposed::False;
-    let mut description = None;
-    let mut ext_key = HashMap::new();
 
     while (space0, keyword_parse("Attribute"), space0)
         .parse_next(input)
@@ -320,6 +412,7 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
             keyword_parse("VB_Creatable").map(|_| Attributes::Creatable),
             keyword_parse("VB_PredeclaredId").map(|_| Attributes::PredeclaredId),
             keyword_parse("VB_Exposed").map(|_| Attributes::Exposed),
+            keyword_parse("VB_TemplateDerived").map(|_| Attributes::TemplateDerived),
             keyword_parse("VB_Description").map(|_| Attributes::Description),
             keyword_parse("VB_Ext_KEY").map(|_| Attributes::ExtKey),
         ))
@@ -335,22 +428,12 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
                     Ok(name) => Some(name),
                     Err(_) => return Err(ErrMode::Cut(VB6ErrorKind::StringParseError)),
                 };
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
             }
             Attributes::Description => {
                 description = match string_parse.parse_next(input) {
                     Ok(description) => Some(description),
                     Err(_) => return Err(ErrMode::Cut(VB6ErrorKind::StringParseError)),
                 };
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
             }
             Attributes::GlobalNameSpace => {
                 global_name_space = match alt((
@@ -364,11 +447,6 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
                         return Err(ErrMode::Cut(VB6ErrorKind::InvalidPropertyValueTrueFalse))
                     }
                 };
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
             }
             Attributes::Creatable => {
                 creatable = match alt((
@@ -382,11 +460,6 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
                         return Err(ErrMode::Cut(VB6ErrorKind::InvalidPropertyValueTrueFalse))
                     }
                 };
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
             }
             Attributes::PredeclaredId => {
                 pre_declared_id = match alt((
@@ -400,11 +473,6 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
                         return Err(ErrMode::Cut(VB6ErrorKind::InvalidPropertyValueTrueFalse))
                     }
                 };
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
             }
             Attributes::Exposed => {
                 exposed = match alt((
@@ -418,11 +486,19 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
                         return Err(ErrMode::Cut(VB6ErrorKind::InvalidPropertyValueTrueFalse))
                     }
                 };
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
+            }
+            Attributes::TemplateDerived => {
+                template_derived = match alt((
+                    literal::<_, _, VB6ErrorKind>("True").map(|_| TemplateDerived::True),
+                    literal::<_, _, VB6ErrorKind>("False").map(|_| TemplateDerived::False),
+                ))
+                .parse_next(input)
+                {
+                    Ok(template_derived) => template_derived,
+                    Err(_) => {
+                        return Err(ErrMode::Cut(VB6ErrorKind::InvalidPropertyValueTrueFalse))
+                    }
+                };
             }
             Attributes::ExtKey => {
                 let Ok(key) = string_parse.parse_next(input) else {
@@ -436,13 +512,11 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
                 };
 
                 ext_key.insert(key, value);
-
-                space0.parse_next(input)?;
-                alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
-
-                continue;
             }
         }
+
+        space0.parse_next(input)?;
+        alt((line_comment_parse, line_ending, eof)).parse_next(input)?;
     }
 
     if name.is_none() {
@@ -455,6 +529,7 @@ pub fn attributes_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6FileAttri
         creatable,
         pre_declared_id,
         exposed,
+        template_derived,
         description,
         ext_key,
     })
@@ -627,6 +702,103 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compiled_object_line_hex_version() {
+        let mut input = VB6Stream::new(
+            "",
+            b"Object={00020430-0000-0000-C000-000000000046}#1.a#0; stdole2.tlb\r\n",
+        );
+
+        let _: Result<&BStr, ErrMode<VB6ErrorKind>> = "Object".parse_next(&mut input);
+
+        let result = object_parse.parse_next(&mut input).unwrap();
+
+        match result {
+            VB6ObjectReference::Compiled { version, .. } => assert_eq!(version, "1.a"),
+            _ => panic!("Expected a compiled object reference."),
+        }
+    }
+
+    #[test]
+    fn compiled_object_line_bad_version() {
+        let mut input = VB6Stream::new(
+            "",
+            b"Object={00020430-0000-0000-C000-000000000046}#x.0#0; stdole2.tlb\r\n",
+        );
+
+        let _: Result<&BStr, ErrMode<VB6ErrorKind>> = "Object".parse_next(&mut input);
+
+        let result = object_parse.parse_next(&mut input);
+
+        assert_eq!(
+            result.err().unwrap().into_inner().unwrap(),
+            VB6ErrorKind::ObjectVersionInvalid
+        );
+        // The error points at the "x", just after "Object={...}#".
+        assert_eq!(input.column, 47);
+
+        let mut input = VB6Stream::new(
+            "",
+            b"Object={00020430-0000-0000-C000-000000000046}##0; stdole2.tlb\r\n",
+        );
+
+        let _: Result<&BStr, ErrMode<VB6ErrorKind>> = "Object".parse_next(&mut input);
+
+        let result = object_parse.parse_next(&mut input);
+
+        assert_eq!(
+            result.err().unwrap().into_inner().unwrap(),
+            VB6ErrorKind::ObjectVersionMissing
+        );
+    }
+
+    #[test]
+    fn compiled_object_line_no_space_after_semicolon() {
+        let mut input = VB6Stream::new(
+            "",
+            b"Object={00020430-0000-0000-C000-000000000046}#2.0#0;stdole2.tlb\r\n",
+        );
+
+        let _: Result<&BStr, ErrMode<VB6ErrorKind>> = "Object".parse_next(&mut input);
+
+        let result = object_parse.parse_next(&mut input).unwrap();
+
+        assert_eq!(input.complete(), 0);
+
+        match result {
+            VB6ObjectReference::Compiled {
+                unknown1,
+                file_name,
+                ..
+            } => {
+                assert_eq!(unknown1, "0");
+                assert_eq!(file_name, "stdole2.tlb");
+            }
+            _ => panic!("Expected a compiled object reference."),
+        }
+    }
+
+    #[test]
+    fn compiled_object_line_space_after_semicolon() {
+        let mut input = VB6Stream::new(
+            "",
+            b"Object={00020430-0000-0000-C000-000000000046}#2.0#0; stdole2.tlb\r\n",
+        );
+
+        let _: Result<&BStr, ErrMode<VB6ErrorKind>> = "Object".parse_next(&mut input);
+
+        let result = object_parse.parse_next(&mut input).unwrap();
+
+        assert_eq!(input.complete(), 0);
+
+        match result {
+            VB6ObjectReference::Compiled { file_name, .. } => {
+                assert_eq!(file_name, "stdole2.tlb");
+            }
+            _ => panic!("Expected a compiled object reference."),
+        }
+    }
+
     #[test]
     fn project_object_line_valid() {
         let mut input = VB6Stream::new("", b"Object=*\\A..\\vbGraph.vbp\r\n");
@@ -706,4 +878,44 @@ mod tests {
         assert_eq!(key, "Attribute1".as_bytes());
         assert_eq!(value, "Value1".as_bytes());
     }
+
+    #[test]
+    fn attribute_name_with_extra_whitespace() {
+        let mut input = VB6Stream::new(
+            "",
+            b"  Attribute   VB_Name  =   \"frmMain\"  \r\n\tAttribute VB_Exposed=True\r\n",
+        );
+
+        let attributes = attributes_parse.parse_next(&mut input).unwrap();
+
+        assert_eq!(attributes.name, "frmMain");
+        assert_eq!(attributes.exposed, Exposed::True);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn attribute_name_with_newline_endings() {
+        let mut input = VB6Stream::new(
+            "",
+            b"Attribute VB_Name = \"Module1\"\nAttribute VB_Creatable = False\n",
+        );
+
+        let attributes = attributes_parse.parse_next(&mut input).unwrap();
+
+        assert_eq!(attributes.name, "Module1");
+        assert_eq!(attributes.creatable, Creatable::False);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn attribute_name_without_quotes() {
+        let mut input = VB6Stream::new("", b"Attribute VB_Name = Module1\r\n");
+
+        let result = attributes_parse.parse_next(&mut input);
+
+        assert_eq!(
+            result.err().unwrap().into_inner().unwrap(),
+            VB6ErrorKind::StringParseError
+        );
+    }
 }
@@ -1,4 +1,7 @@
+mod designer;
+mod encoding;
 mod header;
+mod parseoptions;
 mod vb6stream;
 
 pub mod class;
@@ -6,10 +9,12 @@ pub mod compilesettings;
 pub mod form;
 pub mod module;
 pub mod project;
+pub mod propertypage;
+pub mod usercontrol;
 pub mod vb6;
 
-use bstr::BStr;
-use serde::Serialize;
+use bstr::{BStr, BString};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 pub use class::{
@@ -21,15 +26,24 @@ pub use compilesettings::{
     BoundsCheck, CompilationType, FloatingPointErrorCheck, OverflowCheck, PentiumFDivBugCheck,
     UnroundedFloatingPoint,
 };
+pub use encoding::decode_utf16;
 pub use form::VB6FormFile;
 pub use header::VB6FileAttributes;
 pub use module::VB6ModuleFile;
+pub use parseoptions::ParseOptions;
 
 pub use project::{
-    CompileTargetType, VB6Project, VB6ProjectClass, VB6ProjectModule, VB6ProjectReference,
+    subproject_build_order, CompileTargetType, VB6CircularReference, VB6CompilerSettings,
+    VB6DuplicateMember, VB6MtsSettings, VB6Project, VB6ProjectBuilder, VB6ProjectChange,
+    VB6ProjectClass, VB6ProjectDiff, VB6ProjectMemberKind, VB6ProjectModule, VB6ProjectProperty,
+    VB6ProjectReference, VB6ProjectReferenceOwned, VB6ProjectSummary, VB6StartupObject,
 };
 
-pub use vb6::{is_english_code, vb6_parse};
+pub use propertypage::VB6PropertyPageFile;
+
+pub use usercontrol::VB6UserControlFile;
+
+pub use vb6::{is_english_code, vb6_parse, vb6_parse_spanned};
 
 pub use vb6stream::VB6Stream;
 
@@ -79,3 +93,113 @@ impl Serialize for VB6ObjectReference<'_> {
         }
     }
 }
+
+/// An owned copy of a [`VB6ObjectReference`] which can be deserialized.
+///
+/// `VB6ObjectReference` borrows from the parsed file, so it cannot be read
+/// back from formats such as JSON where the text may be escaped. This type
+/// reads and writes the same fields as the `VB6ObjectReference` serializer.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VB6ObjectReferenceOwned {
+    Compiled {
+        #[serde(with = "uuid_string")]
+        uuid: Uuid,
+        version: BString,
+        unknown1: BString,
+        file_name: BString,
+    },
+    Project {
+        path: BString,
+    },
+}
+
+impl From<&VB6ObjectReference<'_>> for VB6ObjectReferenceOwned {
+    fn from(reference: &VB6ObjectReference<'_>) -> Self {
+        match reference {
+            VB6ObjectReference::Compiled {
+                uuid,
+                version,
+                unknown1,
+                file_name,
+            } => VB6ObjectReferenceOwned::Compiled {
+                uuid: *uuid,
+                version: (*version).to_owned(),
+                unknown1: (*unknown1).to_owned(),
+                file_name: (*file_name).to_owned(),
+            },
+            VB6ObjectReference::Project { path } => VB6ObjectReferenceOwned::Project {
+                path: (*path).to_owned(),
+            },
+        }
+    }
+}
+
+impl VB6ObjectReferenceOwned {
+    /// Borrows this reference as a [`VB6ObjectReference`].
+    #[must_use]
+    pub fn as_reference(&self) -> VB6ObjectReference<'_> {
+        match self {
+            VB6ObjectReferenceOwned::Compiled {
+                uuid,
+                version,
+                unknown1,
+                file_name,
+            } => VB6ObjectReference::Compiled {
+                uuid: *uuid,
+                version: version.as_ref(),
+                unknown1: unknown1.as_ref(),
+                file_name: file_name.as_ref(),
+            },
+            VB6ObjectReferenceOwned::Project { path } => VB6ObjectReference::Project {
+                path: path.as_ref(),
+            },
+        }
+    }
+}
+
+/// Reads and writes a uuid as a string, the same way the reference
+/// serializers do.
+mod uuid_string {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use uuid::Uuid;
+
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&uuid.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        let text = String::deserialize(deserializer)?;
+
+        Uuid::parse_str(&text).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object_reference_round_trip() {
+        let references = [
+            VB6ObjectReference::Compiled {
+                uuid: Uuid::parse_str("831FDD16-0C5C-11D2-A9FC-0000F8754DA1").unwrap(),
+                version: BStr::new("2.0"),
+                unknown1: BStr::new("0"),
+                file_name: BStr::new("MSCOMCTL.OCX"),
+            },
+            VB6ObjectReference::Project {
+                path: BStr::new("..\\Controls\\Controls.vbp"),
+            },
+        ];
+
+        for reference in references {
+            let json = serde_json::to_string(&reference).unwrap();
+            let owned: VB6ObjectReferenceOwned = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(owned, VB6ObjectReferenceOwned::from(&reference));
+            assert_eq!(owned.as_reference(), reference);
+            assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+        }
+    }
+}
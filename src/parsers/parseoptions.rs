@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use crate::errors::VB6ErrorKind;
+
+/// Options that change how a VB6 file is parsed.
+///
+/// The default options match the behavior of the plain `parse` functions.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::errors::VB6ErrorKind;
+/// use vb6parse::parsers::ParseOptions;
+///
+/// let mut options = ParseOptions::default();
+/// options.suppress.insert(VB6ErrorKind::LineTypeUnknown.code());
+///
+/// assert!(options.is_suppressed(&VB6ErrorKind::LineTypeUnknown));
+/// assert!(!options.is_suppressed(&VB6ErrorKind::ProjectTypeUnknown));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Error codes, as returned by [`VB6ErrorKind::code`], which should not
+    /// stop the parse.
+    ///
+    /// Only errors the parser knows how to step past can be suppressed, and
+    /// at the moment that is only [`VB6ErrorKind::LineTypeUnknown`] in
+    /// [`VB6Project::parse_with_options`], where the unknown line is skipped
+    /// instead of being reported. Any other code in the set has no effect,
+    /// and the class, module and form parsers don't take options at all.
+    ///
+    /// [`VB6Project::parse_with_options`]: crate::parsers::VB6Project::parse_with_options
+    pub suppress: HashSet<&'static str>,
+
+    /// The number of columns between tab stops when working out the column
+    /// of an error.
+    ///
+    /// Defaults to 1, which counts a tab as a single column. Set this to the
+    /// tab width of your editor (often 4 or 8) so reported columns line up.
+    ///
+    /// This only changes the `column` field of a [`VB6Error`] returned by
+    /// [`VB6Project::parse_with_options`], the only parser which takes
+    /// options. The other file parsers always count a tab as one column, and
+    /// the rendered report is unaffected since it works from
+    /// `source_offset`.
+    ///
+    /// [`VB6Error`]: crate::errors::VB6Error
+    /// [`VB6Project::parse_with_options`]: crate::parsers::VB6Project::parse_with_options
+    pub tab_width: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            suppress: HashSet::new(),
+            tab_width: 1,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Returns true if errors of this kind should be ignored.
+    #[must_use]
+    pub fn is_suppressed(&self, kind: &VB6ErrorKind) -> bool {
+        self.suppress.contains(kind.code())
+    }
+}
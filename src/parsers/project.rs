@@ -2,15 +2,17 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::str::FromStr;
 
-use bstr::{BStr, ByteSlice};
+use bstr::{BStr, BString, ByteSlice};
+use either::Either;
 use num_enum::TryFromPrimitive;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use uuid::Uuid;
 use winnow::{
-    ascii::{line_ending, space0},
+    ascii::{line_ending, space0, Caseless},
     combinator::{alt, opt},
     error::ErrMode,
-    token::{literal, take_until, take_while},
+    token::{literal, take_till, take_until, take_while},
     Parser,
 };
 
@@ -23,6 +25,7 @@ use crate::{
             UnroundedFloatingPoint,
         },
         header::object_parse,
+        parseoptions::ParseOptions,
         vb6stream::VB6Stream,
         VB6ObjectReference,
     },
@@ -75,7 +78,7 @@ pub struct VB6Project<'a> {
     pub max_number_of_threads: u16,
     pub debug_startup_option: DebugStartupOption,
     pub use_existing_browser: UseExistingBrowser,
-    pub property_page: Option<&'a BStr>,
+    pub property_pages: Vec<&'a BStr>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Default, TryFromPrimitive)]
@@ -235,6 +238,75 @@ impl Serialize for VB6ProjectReference<'_> {
     }
 }
 
+/// An owned copy of a [`VB6ProjectReference`] which can be deserialized.
+///
+/// `VB6ProjectReference` borrows from the parsed file, so it cannot be read
+/// back from formats such as JSON where the text may be escaped. This type
+/// reads and writes the same fields as the `VB6ProjectReference` serializer.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VB6ProjectReferenceOwned {
+    Compiled {
+        #[serde(with = "super::uuid_string")]
+        uuid: Uuid,
+        unknown1: BString,
+        unknown2: BString,
+        path: BString,
+        description: BString,
+    },
+    SubProject {
+        path: BString,
+    },
+}
+
+impl From<&VB6ProjectReference<'_>> for VB6ProjectReferenceOwned {
+    fn from(reference: &VB6ProjectReference<'_>) -> Self {
+        match reference {
+            VB6ProjectReference::Compiled {
+                uuid,
+                unknown1,
+                unknown2,
+                path,
+                description,
+            } => VB6ProjectReferenceOwned::Compiled {
+                uuid: *uuid,
+                unknown1: (*unknown1).to_owned(),
+                unknown2: (*unknown2).to_owned(),
+                path: (*path).to_owned(),
+                description: (*description).to_owned(),
+            },
+            VB6ProjectReference::SubProject { path } => VB6ProjectReferenceOwned::SubProject {
+                path: (*path).to_owned(),
+            },
+        }
+    }
+}
+
+impl VB6ProjectReferenceOwned {
+    /// Borrows this reference as a [`VB6ProjectReference`].
+    #[must_use]
+    pub fn as_reference(&self) -> VB6ProjectReference<'_> {
+        match self {
+            VB6ProjectReferenceOwned::Compiled {
+                uuid,
+                unknown1,
+                unknown2,
+                path,
+                description,
+            } => VB6ProjectReference::Compiled {
+                uuid: *uuid,
+                unknown1: unknown1.as_ref(),
+                unknown2: unknown2.as_ref(),
+                path: path.as_ref(),
+                description: description.as_ref(),
+            },
+            VB6ProjectReferenceOwned::SubProject { path } => VB6ProjectReference::SubProject {
+                path: path.as_ref(),
+            },
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize)]
 pub struct VB6ProjectModule<'a> {
     pub name: &'a BStr,
@@ -247,6 +319,549 @@ pub struct VB6ProjectClass<'a> {
     pub path: &'a BStr,
 }
 
+/// The kind of file a project member is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub enum VB6ProjectMemberKind {
+    Module,
+    Class,
+    Form,
+    UserControl,
+    UserDocument,
+    Designer,
+    RelatedDocument,
+    PropertyPage,
+}
+
+/// A member which is listed more than once in a project file.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct VB6DuplicateMember<'a> {
+    pub kind: VB6ProjectMemberKind,
+    /// The path as written on the repeated line.
+    pub path: &'a BStr,
+}
+
+/// The value of one of the properties of a [`VB6Project`].
+///
+/// Each variant is named after the field of `VB6Project` it holds.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub enum VB6ProjectProperty<'a> {
+    ProjectType(CompileTargetType),
+    UnusedControlInfo(UnusedControlInfo),
+    UpgradeControls(UpgradeControls),
+    ResFile32Path(Option<&'a BStr>),
+    IconForm(Option<&'a BStr>),
+    Startup(Option<&'a BStr>),
+    HelpFilePath(Option<&'a BStr>),
+    Title(Option<&'a BStr>),
+    Exe32FileName(Option<&'a BStr>),
+    Exe32Compatible(Option<&'a BStr>),
+    DllBaseAddress(u32),
+    Path32(Option<&'a BStr>),
+    CommandLineArguments(Option<&'a BStr>),
+    Name(Option<&'a BStr>),
+    Description(Option<&'a BStr>),
+    DebugStartupComponent(Option<&'a BStr>),
+    HelpContextId(Option<&'a BStr>),
+    CompatibilityMode(CompatibilityMode),
+    Version32Compatibility(Option<&'a BStr>),
+    VersionInfo(VersionInformation<'a>),
+    ServerSupportFiles(ServerSupportFiles),
+    ConditionalCompile(Option<&'a BStr>),
+    CompilationType(CompilationType),
+    StartMode(StartMode),
+    Unattended(Unattended),
+    Retained(Retained),
+    ThreadPerObject(Option<u16>),
+    ThreadingModel(ThreadingModel),
+    MaxNumberOfThreads(u16),
+    DebugStartupOption(DebugStartupOption),
+    UseExistingBrowser(UseExistingBrowser),
+}
+
+impl VB6ProjectProperty<'_> {
+    /// Returns the name of the `VB6Project` field the property comes from.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            VB6ProjectProperty::ProjectType(_) => "project_type",
+            VB6ProjectProperty::UnusedControlInfo(_) => "unused_control_info",
+            VB6ProjectProperty::UpgradeControls(_) => "upgrade_controls",
+            VB6ProjectProperty::ResFile32Path(_) => "res_file_32_path",
+            VB6ProjectProperty::IconForm(_) => "icon_form",
+            VB6ProjectProperty::Startup(_) => "startup",
+            VB6ProjectProperty::HelpFilePath(_) => "help_file_path",
+            VB6ProjectProperty::Title(_) => "title",
+            VB6ProjectProperty::Exe32FileName(_) => "exe_32_file_name",
+            VB6ProjectProperty::Exe32Compatible(_) => "exe_32_compatible",
+            VB6ProjectProperty::DllBaseAddress(_) => "dll_base_address",
+            VB6ProjectProperty::Path32(_) => "path_32",
+            VB6ProjectProperty::CommandLineArguments(_) => "command_line_arguments",
+            VB6ProjectProperty::Name(_) => "name",
+            VB6ProjectProperty::Description(_) => "description",
+            VB6ProjectProperty::DebugStartupComponent(_) => "debug_startup_component",
+            VB6ProjectProperty::HelpContextId(_) => "help_context_id",
+            VB6ProjectProperty::CompatibilityMode(_) => "compatibility_mode",
+            VB6ProjectProperty::Version32Compatibility(_) => "version_32_compatibility",
+            VB6ProjectProperty::VersionInfo(_) => "version_info",
+            VB6ProjectProperty::ServerSupportFiles(_) => "server_support_files",
+            VB6ProjectProperty::ConditionalCompile(_) => "conditional_compile",
+            VB6ProjectProperty::CompilationType(_) => "compilation_type",
+            VB6ProjectProperty::StartMode(_) => "start_mode",
+            VB6ProjectProperty::Unattended(_) => "unattended",
+            VB6ProjectProperty::Retained(_) => "retained",
+            VB6ProjectProperty::ThreadPerObject(_) => "thread_per_object",
+            VB6ProjectProperty::ThreadingModel(_) => "threading_model",
+            VB6ProjectProperty::MaxNumberOfThreads(_) => "max_number_of_threads",
+            VB6ProjectProperty::DebugStartupOption(_) => "debug_startup_option",
+            VB6ProjectProperty::UseExistingBrowser(_) => "use_existing_browser",
+        }
+    }
+}
+
+/// A single difference between two VB6 projects.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub enum VB6ProjectChange<'a> {
+    ReferenceAdded(VB6ProjectReference<'a>),
+    ReferenceRemoved(VB6ProjectReference<'a>),
+    ReferenceChanged {
+        old: VB6ProjectReference<'a>,
+        new: VB6ProjectReference<'a>,
+    },
+    ObjectAdded(VB6ObjectReference<'a>),
+    ObjectRemoved(VB6ObjectReference<'a>),
+    ObjectChanged {
+        old: VB6ObjectReference<'a>,
+        new: VB6ObjectReference<'a>,
+    },
+    MemberAdded {
+        kind: VB6ProjectMemberKind,
+        path: &'a BStr,
+    },
+    MemberRemoved {
+        kind: VB6ProjectMemberKind,
+        path: &'a BStr,
+    },
+    /// A module or class kept its path but was given a different name.
+    MemberRenamed {
+        kind: VB6ProjectMemberKind,
+        path: &'a BStr,
+        old: &'a BStr,
+        new: &'a BStr,
+    },
+    PropertyChanged {
+        old: VB6ProjectProperty<'a>,
+        new: VB6ProjectProperty<'a>,
+    },
+    OtherPropertyChanged {
+        section: &'a BStr,
+        name: &'a BStr,
+        old: Option<&'a BStr>,
+        new: Option<&'a BStr>,
+    },
+}
+
+/// The differences between two VB6 projects as found by [`VB6Project::diff`].
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct VB6ProjectDiff<'a> {
+    pub changes: Vec<VB6ProjectChange<'a>>,
+}
+
+impl VB6ProjectDiff<'_> {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A cycle in the sub-project references between projects, as found by
+/// [`subproject_build_order`].
+#[derive(Debug, PartialEq, Eq, Clone, Error)]
+#[error("Circular sub-project reference: {}", .cycle.join(" -> "))]
+pub struct VB6CircularReference {
+    /// The paths of the projects in the cycle. The first path is repeated at
+    /// the end to close the loop.
+    pub cycle: Vec<String>,
+}
+
+/// Works out the order to build a project and all of its sub-projects in.
+///
+/// Starting from `root`, the sub-project references of each project are
+/// followed using `resolve`, which maps a sub-project path to the parsed
+/// project. Projects are identified by the path exactly as it is written in
+/// the referencing project, so `resolve` is the place to deal with relative
+/// paths. A path which `resolve` returns `None` for is treated as a project
+/// without any sub-projects.
+///
+/// # Arguments
+///
+/// * `root` - The path of the project to build.
+/// * `resolve` - Looks up the parsed project for a path.
+///
+/// # Errors
+///
+/// If the sub-project references form a cycle, the projects in the cycle
+/// are returned as a [`VB6CircularReference`].
+///
+/// # Returns
+///
+/// The project paths with every sub-project before the projects which
+/// reference it. `root` is always last.
+pub fn subproject_build_order<'p, 'a: 'p, F>(
+    root: &str,
+    mut resolve: F,
+) -> Result<Vec<String>, VB6CircularReference>
+where
+    F: FnMut(&str) -> Option<&'p VB6Project<'a>>,
+{
+    fn visit<'p, 'a: 'p, F>(
+        path: String,
+        resolve: &mut F,
+        stack: &mut Vec<String>,
+        order: &mut Vec<String>,
+    ) -> Result<(), VB6CircularReference>
+    where
+        F: FnMut(&str) -> Option<&'p VB6Project<'a>>,
+    {
+        if order.contains(&path) {
+            return Ok(());
+        }
+
+        if let Some(start) = stack.iter().position(|visiting| *visiting == path) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(path);
+            return Err(VB6CircularReference { cycle });
+        }
+
+        let sub_projects = resolve(&path)
+            .map(|project| {
+                project
+                    .get_subproject_references()
+                    .into_iter()
+                    .filter_map(|reference| match reference {
+                        VB6ProjectReference::SubProject { path } => Some(path.to_string()),
+                        VB6ProjectReference::Compiled { .. } => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        stack.push(path);
+        for sub_project in sub_projects {
+            visit(sub_project, resolve, stack, order)?;
+        }
+        let path = stack.pop().unwrap();
+
+        order.push(path);
+        Ok(())
+    }
+
+    let mut order = vec![];
+    visit(root.to_owned(), &mut resolve, &mut vec![], &mut order)?;
+
+    Ok(order)
+}
+
+/// Builds a [`VB6Project`] in code rather than by parsing a project file.
+///
+/// The builder starts from the settings VB6 uses for a new project, so only
+/// what differs needs to be set.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::{CompileTargetType, VB6ProjectBuilder};
+///
+/// let project = VB6ProjectBuilder::new(CompileTargetType::Exe)
+///     .name("Calculator")
+///     .form("frmMain.frm")
+///     .startup("frmMain")
+///     .build();
+///
+/// assert_eq!(project.name, Some("Calculator".into()));
+/// assert_eq!(project.forms.len(), 1);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VB6ProjectBuilder<'a> {
+    project: VB6Project<'a>,
+}
+
+impl<'a> VB6ProjectBuilder<'a> {
+    /// Creates a builder for a new project of the given type.
+    ///
+    /// The project is named `Project1`, has version 1.0.0, compiles to
+    /// p-code and has no members or references.
+    #[must_use]
+    pub fn new(project_type: CompileTargetType) -> Self {
+        let empty = Some(BStr::new(b""));
+
+        let project = VB6Project {
+            project_type,
+            references: vec![],
+            objects: vec![],
+            modules: vec![],
+            classes: vec![],
+            related_documents: vec![],
+            designers: vec![],
+            forms: vec![],
+            user_controls: vec![],
+            user_documents: vec![],
+            other_properties: HashMap::new(),
+            unused_control_info: UnusedControlInfo::default(),
+            upgrade_controls: UpgradeControls::default(),
+            res_file_32_path: empty,
+            icon_form: empty,
+            startup: empty,
+            help_file_path: empty,
+            title: Some(BStr::new("Project1")),
+            exe_32_file_name: empty,
+            exe_32_compatible: empty,
+            dll_base_address: 0x1100_0000_u32,
+            path_32: empty,
+            command_line_arguments: empty,
+            name: Some(BStr::new("Project1")),
+            description: empty,
+            debug_startup_component: empty,
+            help_context_id: Some(BStr::new("0")),
+            compatibility_mode: CompatibilityMode::default(),
+            version_32_compatibility: empty,
+            version_info: VersionInformation {
+                major: 1,
+                minor: 0,
+                revision: 0,
+                auto_increment_revision: 0,
+                company_name: empty,
+                file_description: empty,
+                copyright: empty,
+                trademark: empty,
+                product_name: empty,
+                comments: empty,
+            },
+            server_support_files: ServerSupportFiles::default(),
+            conditional_compile: empty,
+            compilation_type: CompilationType::PCode,
+            start_mode: StartMode::default(),
+            unattended: Unattended::default(),
+            retained: Retained::default(),
+            thread_per_object: Some(0),
+            threading_model: ThreadingModel::default(),
+            max_number_of_threads: 1,
+            debug_startup_option: DebugStartupOption::default(),
+            use_existing_browser: UseExistingBrowser::default(),
+            property_pages: vec![],
+        };
+
+        VB6ProjectBuilder { project }
+    }
+
+    /// Sets the project name, used as the name of the compiled component.
+    #[must_use]
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.project.name = Some(BStr::new(name));
+        self
+    }
+
+    /// Sets the application title.
+    #[must_use]
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.project.title = Some(BStr::new(title));
+        self
+    }
+
+    /// Sets the project description.
+    #[must_use]
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.project.description = Some(BStr::new(description));
+        self
+    }
+
+    /// Sets the startup object, either a form name or `Sub Main`.
+    #[must_use]
+    pub fn startup(mut self, startup: &'a str) -> Self {
+        self.project.startup = Some(BStr::new(startup));
+        self
+    }
+
+    /// Sets the file name of the compiled executable.
+    #[must_use]
+    pub fn exe_32_file_name(mut self, file_name: &'a str) -> Self {
+        self.project.exe_32_file_name = Some(BStr::new(file_name));
+        self
+    }
+
+    /// Sets the major, minor and revision version numbers.
+    #[must_use]
+    pub fn version(mut self, major: u16, minor: u16, revision: u16) -> Self {
+        self.project.version_info.major = major;
+        self.project.version_info.minor = minor;
+        self.project.version_info.revision = revision;
+        self
+    }
+
+    /// Sets how the project is compiled.
+    #[must_use]
+    pub fn compilation_type(mut self, compilation_type: CompilationType) -> Self {
+        self.project.compilation_type = compilation_type;
+        self
+    }
+
+    /// Adds a reference to a type library or sub-project.
+    #[must_use]
+    pub fn reference(mut self, reference: VB6ProjectReference<'a>) -> Self {
+        self.project.references.push(reference);
+        self
+    }
+
+    /// Adds a compiled control library (`.ocx`) used by the project.
+    #[must_use]
+    pub fn object(mut self, object: VB6ObjectReference<'a>) -> Self {
+        self.project.objects.push(object);
+        self
+    }
+
+    /// Adds a standard module.
+    #[must_use]
+    pub fn module(mut self, name: &'a str, path: &'a str) -> Self {
+        self.project.modules.push(VB6ProjectModule {
+            name: BStr::new(name),
+            path: BStr::new(path),
+        });
+        self
+    }
+
+    /// Adds a class module.
+    #[must_use]
+    pub fn class(mut self, name: &'a str, path: &'a str) -> Self {
+        self.project.classes.push(VB6ProjectClass {
+            name: BStr::new(name),
+            path: BStr::new(path),
+        });
+        self
+    }
+
+    /// Adds a form.
+    #[must_use]
+    pub fn form(mut self, path: &'a str) -> Self {
+        self.project.forms.push(BStr::new(path));
+        self
+    }
+
+    /// Adds a user control.
+    #[must_use]
+    pub fn user_control(mut self, path: &'a str) -> Self {
+        self.project.user_controls.push(BStr::new(path));
+        self
+    }
+
+    /// Adds a user document.
+    #[must_use]
+    pub fn user_document(mut self, path: &'a str) -> Self {
+        self.project.user_documents.push(BStr::new(path));
+        self
+    }
+
+    /// Adds a designer.
+    #[must_use]
+    pub fn designer(mut self, path: &'a str) -> Self {
+        self.project.designers.push(BStr::new(path));
+        self
+    }
+
+    /// Adds a related document.
+    #[must_use]
+    pub fn related_document(mut self, path: &'a str) -> Self {
+        self.project.related_documents.push(BStr::new(path));
+        self
+    }
+
+    /// Adds a property page.
+    #[must_use]
+    pub fn property_page(mut self, path: &'a str) -> Self {
+        self.project.property_pages.push(BStr::new(path));
+        self
+    }
+
+    /// Returns the finished project.
+    #[must_use]
+    pub fn build(self) -> VB6Project<'a> {
+        self.project
+    }
+}
+
+/// The settings from the `[MS Transaction Server]` section of a project file.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct VB6MtsSettings {
+    /// `AutoRefresh`, which refreshes the component in MTS whenever the
+    /// project is rebuilt.
+    pub auto_refresh: bool,
+}
+
+/// The settings from the `[VBCompiler]` section of a project file.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct VB6CompilerSettings<'a> {
+    /// Extra switches passed to the linker.
+    pub link_switches: Option<&'a BStr>,
+    /// The comment which is embedded in the compiled file.
+    pub comment: Option<&'a BStr>,
+}
+
+/// The number of each kind of entry in a project file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize)]
+pub struct VB6ProjectSummary {
+    pub compiled_references: usize,
+    pub subproject_references: usize,
+    pub objects: usize,
+    pub modules: usize,
+    pub classes: usize,
+    pub forms: usize,
+    pub user_controls: usize,
+    pub user_documents: usize,
+    pub designers: usize,
+    pub related_documents: usize,
+    pub property_pages: usize,
+    /// The number of `[Section]` blocks kept in `other_properties`.
+    pub other_sections: usize,
+}
+
+/// The entry point named by the `Startup` line of a project file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub enum VB6StartupObject<'a> {
+    /// The project has no startup object, written as `!(None)!`.
+    None,
+    /// The project starts by calling `Sub Main`.
+    SubMain,
+    /// The project starts by showing the named form.
+    Form(&'a BStr),
+    /// The startup object is not `Sub Main` and does not match the file
+    /// name of any form in the project.
+    Unknown(&'a BStr),
+}
+
+/// Iterates over the references of a project.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::VB6Project;
+///
+/// let input = b"Type=Exe\r\nReference=*\\Aproject1.vbp\r\nReference=*\\Aproject2.vbp\r\n";
+/// let project = VB6Project::parse("project.vbp", input).unwrap();
+///
+/// let mut count = 0;
+/// for _reference in &project {
+///     count += 1;
+/// }
+///
+/// assert_eq!(count, 2);
+/// ```
+impl<'p, 'a> IntoIterator for &'p VB6Project<'a> {
+    type Item = &'p VB6ProjectReference<'a>;
+    type IntoIter = std::slice::Iter<'p, VB6ProjectReference<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.references.iter()
+    }
+}
+
 impl<'a> VB6Project<'a> {
     /// Parses a VB6 project file.
     ///
@@ -262,10 +877,6 @@ impl<'a> VB6Project<'a> {
     ///
     /// This function can return a `VB6Error` if the input is not a valid VB6 project file.
     ///
-    /// # Panics
-    ///
-    /// This function can panic if the input is not a valid VB6 project file.
-    ///
     /// # Example
     ///
     /// ```rust
@@ -344,7 +955,50 @@ impl<'a> VB6Project<'a> {
     /// assert_eq!(project.exe_32_file_name, Some(BStr::new(b"Project1.exe")));
     /// ```
     pub fn parse(file_name: impl Into<String>, source_code: &'a [u8]) -> Result<Self, VB6Error> {
-        let mut input = VB6Stream::new(file_name, source_code);
+        VB6Project::parse_with_options(file_name, source_code, &ParseOptions::default())
+    }
+
+    /// Parses a VB6 project file using the given parse options.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The name of the file being parsed.
+    /// * `source_code` - The source code of the file being parsed.
+    /// * `options` - The options which change how the file is parsed.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the parsed VB6 project or an error.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the input is not a valid VB6 project file.
+    /// Unknown lines are skipped instead when the `LineTypeUnknown` error code
+    /// is suppressed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::errors::VB6ErrorKind;
+    /// use vb6parse::parsers::{ParseOptions, VB6Project};
+    ///
+    /// let input = b"Type=Exe\r\nToolKey=1\r\nTitle=\"Project1\"\r\n";
+    ///
+    /// assert!(VB6Project::parse("project1.vbp", input).is_err());
+    ///
+    /// let mut options = ParseOptions::default();
+    /// options.suppress.insert(VB6ErrorKind::LineTypeUnknown.code());
+    ///
+    /// let project = VB6Project::parse_with_options("project1.vbp", input, &options).unwrap();
+    ///
+    /// assert_eq!(project.title, Some("Project1".into()));
+    /// ```
+    pub fn parse_with_options(
+        file_name: impl Into<String>,
+        source_code: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<Self, VB6Error> {
+        let mut input = VB6Stream::new(file_name, source_code).with_tab_width(options.tab_width);
 
         let mut references = vec![];
         let mut user_documents = vec![];
@@ -407,7 +1061,7 @@ impl<'a> VB6Project<'a> {
         let mut product_name = Some(BStr::new(b""));
         let mut comments = Some(BStr::new(b""));
         let mut use_existing_browser = UseExistingBrowser::Use;
-        let mut property_page = Some(BStr::new(b""));
+        let mut property_pages = vec![];
 
         let mut other_property_group: Option<&'a BStr> = None;
 
@@ -446,17 +1100,19 @@ impl<'a> VB6Project<'a> {
 
             // Looks like we are no longer parsing the standard VB6 property section
             // Now we are parsing some third party properties.
-            if other_property_group.is_some() {
+            if let Some(other_property_group) = other_property_group {
                 let (property_name, property_value) = match other_property_parse(&mut input) {
                     Ok((property_name, property_value)) => (property_name, property_value),
                     Err(e) => {
-                        return Err(input.error(e.into_inner().unwrap()));
+                        return Err(
+                            input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable))
+                        );
                     }
                 };
 
                 other_properties
-                    .get_mut(other_property_group.unwrap())
-                    .unwrap()
+                    .entry(other_property_group)
+                    .or_insert_with(HashMap::new)
                     .insert(property_name, property_value);
 
                 continue;
@@ -471,7 +1127,9 @@ impl<'a> VB6Project<'a> {
             {
                 project_type = match project_type_parse.parse_next(&mut input) {
                     Ok(project_type) => Some(project_type),
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 continue;
@@ -483,7 +1141,9 @@ impl<'a> VB6Project<'a> {
             {
                 let designer = match designer_parse.parse_next(&mut input) {
                     Ok(designer) => designer,
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 designers.push(designer);
@@ -497,7 +1157,9 @@ impl<'a> VB6Project<'a> {
             {
                 let reference = match reference_parse.parse_next(&mut input) {
                     Ok(reference) => reference,
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 references.push(reference);
@@ -511,7 +1173,9 @@ impl<'a> VB6Project<'a> {
             {
                 let object = match object_parse.parse_next(&mut input) {
                     Ok(object) => object,
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 objects.push(object);
@@ -525,7 +1189,9 @@ impl<'a> VB6Project<'a> {
             {
                 let module = match module_parse.parse_next(&mut input) {
                     Ok(module) => module,
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 modules.push(module);
@@ -539,7 +1205,9 @@ impl<'a> VB6Project<'a> {
             {
                 let class = match class_parse.parse_next(&mut input) {
                     Ok(class) => class,
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 classes.push(class);
@@ -704,7 +1372,9 @@ impl<'a> VB6Project<'a> {
             {
                 title = match title_parse.parse_next(&mut input) {
                     Ok(title) => Some(title),
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 continue;
@@ -781,7 +1451,9 @@ impl<'a> VB6Project<'a> {
             {
                 compatibility_mode = match compatibility_mode_parse.parse_next(&mut input) {
                     Ok(compatibility_mode) => compatibility_mode,
-                    Err(e) => return Err(input.error(e.into_inner().unwrap())),
+                    Err(e) => {
+                        return Err(input.error(e.into_inner().unwrap_or(VB6ErrorKind::Unparseable)))
+                    }
                 };
 
                 continue;
@@ -887,13 +1559,13 @@ impl<'a> VB6Project<'a> {
                     return Err(input.error(VB6ErrorKind::NoEqualSplit));
                 };
 
-                property_page = match take_until_line_ending.parse_next(&mut input) {
-                    Ok(property_page) => Some(property_page),
-                    Err(_) => {
-                        return Err(input.error(VB6ErrorKind::PropertyPageUnparseable));
-                    }
+                let Ok(property_page): VB6Result<_> = take_until_line_ending.parse_next(&mut input)
+                else {
+                    return Err(input.error(VB6ErrorKind::PropertyPageUnparseable));
                 };
 
+                property_pages.push(property_page);
+
                 if (space0, alt((line_ending, line_comment_parse)))
                     .parse_next(&mut input)
                     .is_err()
@@ -1224,12 +1896,23 @@ impl<'a> VB6Project<'a> {
                 continue;
             }
 
+            if options.is_suppressed(&VB6ErrorKind::LineTypeUnknown) {
+                let start_index = input.index;
+
+                let _: VB6Result<_> =
+                    (take_till(0.., ('\r', '\n')), opt(line_ending)).parse_next(&mut input);
+
+                if input.index != start_index {
+                    continue;
+                }
+            }
+
             return Err(input.error(VB6ErrorKind::LineTypeUnknown));
         }
 
-        if project_type.is_none() {
+        let Some(project_type) = project_type else {
             return Err(input.error(VB6ErrorKind::FirstLineNotProject));
-        }
+        };
 
         let version_info = VersionInformation {
             major,
@@ -1260,20 +1943,19 @@ impl<'a> VB6Project<'a> {
         }
 
         let project = VB6Project {
-            project_type: project_type.unwrap(),
+            project_type,
             references,
             objects,
             modules,
             classes,
+            related_documents,
             designers,
             forms,
             user_controls,
             user_documents,
-            related_documents,
             other_properties,
             unused_control_info,
             upgrade_controls,
-            debug_startup_component,
             res_file_32_path,
             icon_form,
             startup,
@@ -1282,13 +1964,14 @@ impl<'a> VB6Project<'a> {
             exe_32_file_name,
             exe_32_compatible,
             dll_base_address,
-            version_32_compatibility,
             path_32,
             command_line_arguments,
             name,
             description,
+            debug_startup_component,
             help_context_id,
             compatibility_mode,
+            version_32_compatibility,
             version_info,
             server_support_files,
             conditional_compile,
@@ -1297,31 +1980,622 @@ impl<'a> VB6Project<'a> {
             unattended,
             retained,
             thread_per_object,
-            max_number_of_threads,
             threading_model,
+            max_number_of_threads,
             debug_startup_option,
             use_existing_browser,
-            property_page,
+            property_pages,
         };
 
         Ok(project)
     }
 
+    /// Returns an iterator over the references of the project.
+    ///
+    /// This is the same as iterating over `&project`.
+    pub fn iter(&self) -> std::slice::Iter<'_, VB6ProjectReference<'a>> {
+        self.references.iter()
+    }
+
+    #[must_use]
+    pub fn get_subproject_references(&self) -> Vec<&VB6ProjectReference> {
+        self.references
+            .iter()
+            .filter(|reference| matches!(reference, VB6ProjectReference::SubProject { .. }))
+            .collect::<Vec<_>>()
+    }
+
+    #[must_use]
+    pub fn get_compiled_references(&self) -> Vec<&VB6ProjectReference> {
+        self.references
+            .iter()
+            .filter(|reference| matches!(reference, VB6ProjectReference::Compiled { .. }))
+            .collect::<Vec<_>>()
+    }
+
+    /// Finds the compiled reference with the given uuid.
+    ///
+    /// The uuid is compared by value, so the letter case used in the project
+    /// file does not matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - The uuid of the type library to look for.
+    ///
+    /// # Returns
+    ///
+    /// The matching compiled reference, or `None` if the project does not
+    /// reference the uuid.
+    #[must_use]
+    pub fn reference_by_uuid(&self, uuid: &Uuid) -> Option<&VB6ProjectReference<'a>> {
+        self.references.iter().find(|reference| {
+            matches!(reference, VB6ProjectReference::Compiled { uuid: reference_uuid, .. } if reference_uuid == uuid)
+        })
+    }
+
+    /// Finds the compiled object with the given uuid.
+    ///
+    /// The uuid is compared by value, so the letter case used in the project
+    /// file does not matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - The uuid of the object to look for.
+    ///
+    /// # Returns
+    ///
+    /// The matching compiled object, or `None` if the project does not use
+    /// the uuid.
+    #[must_use]
+    pub fn object_by_uuid(&self, uuid: &Uuid) -> Option<&VB6ObjectReference<'a>> {
+        self.objects.iter().find(|object| {
+            matches!(object, VB6ObjectReference::Compiled { uuid: object_uuid, .. } if object_uuid == uuid)
+        })
+    }
+
+    /// Returns the settings from the `[MS Transaction Server]` section.
+    ///
+    /// The raw values are still available in `other_properties`.
+    ///
+    /// # Returns
+    ///
+    /// The settings, or `None` if the project has no such section.
+    #[must_use]
+    pub fn mts_settings(&self) -> Option<VB6MtsSettings> {
+        let section = self
+            .other_properties
+            .get(BStr::new("MS Transaction Server"))?;
+
+        let auto_refresh = section
+            .get(BStr::new("AutoRefresh"))
+            .is_some_and(|value| value.trim() != b"0");
+
+        Some(VB6MtsSettings { auto_refresh })
+    }
+
+    /// Returns the settings from the `[VBCompiler]` section.
+    ///
+    /// The raw values are still available in `other_properties`.
+    ///
+    /// # Returns
+    ///
+    /// The settings, or `None` if the project has no such section.
+    #[must_use]
+    pub fn compiler_settings(&self) -> Option<VB6CompilerSettings<'a>> {
+        let section = self.other_properties.get(BStr::new("VBCompiler"))?;
+
+        Some(VB6CompilerSettings {
+            link_switches: section.get(BStr::new("LinkSwitches")).copied(),
+            comment: section.get(BStr::new("Comment")).copied(),
+        })
+    }
+
+    /// Finds members which are listed more than once.
+    ///
+    /// Paths are compared ignoring case, since VB6 projects live on Windows
+    /// file systems. Each repeat of a path is reported once, so a form
+    /// listed three times gives two entries. The members themselves are
+    /// left as they were parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::{VB6DuplicateMember, VB6Project, VB6ProjectMemberKind};
+    ///
+    /// let input = b"Type=Exe\r\nForm=Form1.frm\r\nForm=Form2.frm\r\nForm=form1.frm\r\n";
+    /// let project = VB6Project::parse("project.vbp", input).unwrap();
+    ///
+    /// assert_eq!(
+    ///     project.duplicate_members(),
+    ///     vec![VB6DuplicateMember {
+    ///         kind: VB6ProjectMemberKind::Form,
+    ///         path: "form1.frm".into(),
+    ///     }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn duplicate_members(&self) -> Vec<VB6DuplicateMember<'a>> {
+        let mut duplicates = vec![];
+
+        for (kind, paths) in self.member_paths() {
+            for (index, path) in paths.iter().enumerate() {
+                if paths[..index]
+                    .iter()
+                    .any(|earlier| member_key(earlier) == member_key(path))
+                {
+                    duplicates.push(VB6DuplicateMember { kind, path });
+                }
+            }
+        }
+
+        duplicates
+    }
+
+    /// Counts the references, objects and members of the project.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Project;
+    ///
+    /// let project = VB6Project::parse("a.vbp", b"Type=Exe\r\nForm=A.frm\r\nForm=B.frm\r\n").unwrap();
+    /// let summary = project.summary();
+    ///
+    /// assert_eq!(summary.forms, 2);
+    /// assert_eq!(summary.modules, 0);
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> VB6ProjectSummary {
+        let subproject_references = self
+            .references
+            .iter()
+            .filter(|reference| matches!(reference, VB6ProjectReference::SubProject { .. }))
+            .count();
+
+        VB6ProjectSummary {
+            compiled_references: self.references.len() - subproject_references,
+            subproject_references,
+            objects: self.objects.len(),
+            modules: self.modules.len(),
+            classes: self.classes.len(),
+            forms: self.forms.len(),
+            user_controls: self.user_controls.len(),
+            user_documents: self.user_documents.len(),
+            designers: self.designers.len(),
+            related_documents: self.related_documents.len(),
+            property_pages: self.property_pages.len(),
+            other_sections: self.other_properties.len(),
+        }
+    }
+
+    /// Splits the `CondComp` line into the conditional compilation constants
+    /// it defines.
+    ///
+    /// The line holds `name = value` pairs separated by colons, such as
+    /// `"DEBUGMODE = 1 : RELEASE = 0"`. Names and values are trimmed, and
+    /// entries without an `=` are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Project;
+    ///
+    /// let project = VB6Project::parse("a.vbp", b"Type=Exe\r\nCondComp=\"DEBUGMODE = 1\"\r\n").unwrap();
+    /// let constants = project.conditional_compile_constants();
+    ///
+    /// assert_eq!(constants, vec![("DEBUGMODE".into(), "1".into())]);
+    /// ```
+    #[must_use]
+    pub fn conditional_compile_constants(&self) -> Vec<(&'a BStr, &'a BStr)> {
+        let Some(conditional_compile) = self.conditional_compile else {
+            return vec![];
+        };
+        let conditional_compile: &'a [u8] = conditional_compile;
+
+        conditional_compile
+            .split_str(":")
+            .filter_map(|constant| {
+                let (name, value) = constant.split_once_str("=")?;
+
+                Some((name.trim().as_bstr(), value.trim().as_bstr()))
+            })
+            .collect()
+    }
+
+    /// Resolves the `Startup` line into the entry point of the project.
+    ///
+    /// The form files are not read, so a form is matched by comparing the
+    /// startup name against the file name of each form, ignoring case and
+    /// the extension. VB6 names new form files after the form, so this
+    /// matches unless a form was renamed without renaming its file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::{VB6Project, VB6StartupObject};
+    ///
+    /// let project = VB6Project::parse(
+    ///     "a.vbp",
+    ///     b"Type=Exe\r\nForm=Forms\\frmMain.frm\r\nStartup=\"frmMain\"\r\n",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(project.startup_object(), VB6StartupObject::Form("frmMain".into()));
+    /// ```
+    #[must_use]
+    pub fn startup_object(&self) -> VB6StartupObject<'a> {
+        // a project without a `Startup` line is parsed, and built, with an
+        // empty startup rather than `None`.
+        let Some(startup) = self.startup.filter(|startup| !startup.is_empty()) else {
+            return VB6StartupObject::None;
+        };
+
+        if startup.eq_ignore_ascii_case(b"Sub Main") {
+            return VB6StartupObject::SubMain;
+        }
+
+        let is_form = self.forms.iter().any(|path| {
+            let path: &[u8] = path;
+            let file_name = path.rsplit_once_str("\\").map_or(path, |(_, name)| name);
+            let stem = file_name
+                .rsplit_once_str(".")
+                .map_or(file_name, |(stem, _)| stem);
+
+            stem.eq_ignore_ascii_case(startup)
+        });
+
+        if is_form {
+            VB6StartupObject::Form(startup)
+        } else {
+            VB6StartupObject::Unknown(startup)
+        }
+    }
+
+    /// Returns the first `PropertyPage` entry of the project.
+    ///
+    /// Projects used to keep a single property page in a `property_page`
+    /// field, which dropped every entry after the first.
+    #[deprecated(note = "a project can have several property pages, use `property_pages`")]
+    #[must_use]
+    pub fn property_page(&self) -> Option<&'a BStr> {
+        self.property_pages.first().copied()
+    }
+
+    /// Returns the number of files which make up the project.
+    ///
+    /// Every kind of member in `VB6ProjectMemberKind` is counted, including
+    /// related documents. References and objects are not members.
+    #[must_use]
+    pub fn member_count(&self) -> usize {
+        self.modules.len()
+            + self.classes.len()
+            + self.forms.len()
+            + self.user_controls.len()
+            + self.user_documents.len()
+            + self.designers.len()
+            + self.related_documents.len()
+            + self.property_pages.len()
+    }
+
+    /// Returns true if the two projects describe the same project, ignoring
+    /// the order entries are listed in.
+    ///
+    /// References, objects and members are compared as sets, and every
+    /// other property by value. The derived `PartialEq` is still available
+    /// for an exact comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The project to compare against.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Project;
+    ///
+    /// let first = VB6Project::parse("a.vbp", b"Type=Exe\r\nForm=A.frm\r\nForm=B.frm\r\n").unwrap();
+    /// let second = VB6Project::parse("b.vbp", b"Type=Exe\r\nForm=B.frm\r\nForm=A.frm\r\n").unwrap();
+    ///
+    /// assert!(first.semantically_eq(&second));
+    /// assert_ne!(first, second);
+    /// ```
+    #[must_use]
+    pub fn semantically_eq(&self, other: &VB6Project<'a>) -> bool {
+        self.diff(other).is_empty()
+    }
+
+    /// Compares this project against another version of the same project.
+    ///
+    /// The result lists what changed going from `self` to `other`. References
+    /// and objects are matched by uuid (or by path for sub-projects) and
+    /// members by path, so a different order of the same entries is not
+    /// reported as a change. Like VB6, member paths are matched without
+    /// regard to case. A module or class which keeps its path but changes
+    /// its name is reported as renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The newer version of the project.
+    ///
+    /// # Returns
+    ///
+    /// The differences between the two projects.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Project;
+    ///
+    /// let old = VB6Project::parse("old.vbp", b"Type=Exe\r\nForm=Form1.frm\r\n").unwrap();
+    /// let new = VB6Project::parse("new.vbp", b"Type=Exe\r\nForm=Form1.frm\r\n").unwrap();
+    ///
+    /// assert!(old.diff(&new).is_empty());
+    /// ```
     #[must_use]
-    pub fn get_subproject_references(&self) -> Vec<&VB6ProjectReference> {
-        self.references
-            .iter()
-            .filter(|reference| matches!(reference, VB6ProjectReference::SubProject { .. }))
-            .collect::<Vec<_>>()
+    pub fn diff(&self, other: &VB6Project<'a>) -> VB6ProjectDiff<'a> {
+        let mut changes = vec![];
+
+        let (added, removed) = diff_by_key(&self.references, &other.references, reference_key);
+        changes.extend(
+            added
+                .into_iter()
+                .map(|reference| VB6ProjectChange::ReferenceAdded(reference.clone())),
+        );
+        changes.extend(
+            removed
+                .into_iter()
+                .map(|reference| VB6ProjectChange::ReferenceRemoved(reference.clone())),
+        );
+        changes.extend(
+            changed_by_key(&self.references, &other.references, reference_key)
+                .into_iter()
+                .map(|(old, new)| VB6ProjectChange::ReferenceChanged {
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+        );
+
+        let (added, removed) = diff_by_key(&self.objects, &other.objects, object_key);
+        changes.extend(
+            added
+                .into_iter()
+                .map(|object| VB6ProjectChange::ObjectAdded(object.clone())),
+        );
+        changes.extend(
+            removed
+                .into_iter()
+                .map(|object| VB6ProjectChange::ObjectRemoved(object.clone())),
+        );
+        changes.extend(
+            changed_by_key(&self.objects, &other.objects, object_key)
+                .into_iter()
+                .map(|(old, new)| VB6ProjectChange::ObjectChanged {
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+        );
+
+        let members = self.member_paths().into_iter().zip(other.member_paths());
+
+        for ((kind, old_paths), (_, new_paths)) in members {
+            let (added, removed) = diff_by_key(&old_paths, &new_paths, |path| member_key(path));
+            changes.extend(
+                added
+                    .into_iter()
+                    .map(|path| VB6ProjectChange::MemberAdded { kind, path }),
+            );
+            changes.extend(
+                removed
+                    .into_iter()
+                    .map(|path| VB6ProjectChange::MemberRemoved { kind, path }),
+            );
+        }
+
+        let module_path = |module: &VB6ProjectModule<'a>| member_key(module.path);
+        changes.extend(
+            changed_by_key(&self.modules, &other.modules, module_path)
+                .into_iter()
+                .filter(|(old, new)| old.name != new.name)
+                .map(|(old, new)| VB6ProjectChange::MemberRenamed {
+                    kind: VB6ProjectMemberKind::Module,
+                    path: new.path,
+                    old: old.name,
+                    new: new.name,
+                }),
+        );
+
+        let class_path = |class: &VB6ProjectClass<'a>| member_key(class.path);
+        changes.extend(
+            changed_by_key(&self.classes, &other.classes, class_path)
+                .into_iter()
+                .filter(|(old, new)| old.name != new.name)
+                .map(|(old, new)| VB6ProjectChange::MemberRenamed {
+                    kind: VB6ProjectMemberKind::Class,
+                    path: new.path,
+                    old: old.name,
+                    new: new.name,
+                }),
+        );
+
+        self.diff_properties(other, &mut changes);
+        self.diff_other_properties(other, &mut changes);
+
+        VB6ProjectDiff { changes }
     }
 
-    #[must_use]
-    pub fn get_compiled_references(&self) -> Vec<&VB6ProjectReference> {
-        self.references
-            .iter()
-            .filter(|reference| matches!(reference, VB6ProjectReference::Compiled { .. }))
-            .collect::<Vec<_>>()
+    fn member_paths(&self) -> [(VB6ProjectMemberKind, Vec<&'a BStr>); 8] {
+        [
+            (
+                VB6ProjectMemberKind::Module,
+                self.modules.iter().map(|module| module.path).collect(),
+            ),
+            (
+                VB6ProjectMemberKind::Class,
+                self.classes.iter().map(|class| class.path).collect(),
+            ),
+            (VB6ProjectMemberKind::Form, self.forms.clone()),
+            (
+                VB6ProjectMemberKind::UserControl,
+                self.user_controls.clone(),
+            ),
+            (
+                VB6ProjectMemberKind::UserDocument,
+                self.user_documents.clone(),
+            ),
+            (VB6ProjectMemberKind::Designer, self.designers.clone()),
+            (
+                VB6ProjectMemberKind::RelatedDocument,
+                self.related_documents.clone(),
+            ),
+            (
+                VB6ProjectMemberKind::PropertyPage,
+                self.property_pages.clone(),
+            ),
+        ]
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn properties(&self) -> Vec<VB6ProjectProperty<'a>> {
+        vec![
+            VB6ProjectProperty::ProjectType(self.project_type.clone()),
+            VB6ProjectProperty::UnusedControlInfo(self.unused_control_info.clone()),
+            VB6ProjectProperty::UpgradeControls(self.upgrade_controls.clone()),
+            VB6ProjectProperty::ResFile32Path(self.res_file_32_path),
+            VB6ProjectProperty::IconForm(self.icon_form),
+            VB6ProjectProperty::Startup(self.startup),
+            VB6ProjectProperty::HelpFilePath(self.help_file_path),
+            VB6ProjectProperty::Title(self.title),
+            VB6ProjectProperty::Exe32FileName(self.exe_32_file_name),
+            VB6ProjectProperty::Exe32Compatible(self.exe_32_compatible),
+            VB6ProjectProperty::DllBaseAddress(self.dll_base_address),
+            VB6ProjectProperty::Path32(self.path_32),
+            VB6ProjectProperty::CommandLineArguments(self.command_line_arguments),
+            VB6ProjectProperty::Name(self.name),
+            VB6ProjectProperty::Description(self.description),
+            VB6ProjectProperty::DebugStartupComponent(self.debug_startup_component),
+            VB6ProjectProperty::HelpContextId(self.help_context_id),
+            VB6ProjectProperty::CompatibilityMode(self.compatibility_mode.clone()),
+            VB6ProjectProperty::Version32Compatibility(self.version_32_compatibility),
+            VB6ProjectProperty::VersionInfo(self.version_info.clone()),
+            VB6ProjectProperty::ServerSupportFiles(self.server_support_files.clone()),
+            VB6ProjectProperty::ConditionalCompile(self.conditional_compile),
+            VB6ProjectProperty::CompilationType(self.compilation_type.clone()),
+            VB6ProjectProperty::StartMode(self.start_mode.clone()),
+            VB6ProjectProperty::Unattended(self.unattended.clone()),
+            VB6ProjectProperty::Retained(self.retained.clone()),
+            VB6ProjectProperty::ThreadPerObject(self.thread_per_object),
+            VB6ProjectProperty::ThreadingModel(self.threading_model.clone()),
+            VB6ProjectProperty::MaxNumberOfThreads(self.max_number_of_threads),
+            VB6ProjectProperty::DebugStartupOption(self.debug_startup_option.clone()),
+            VB6ProjectProperty::UseExistingBrowser(self.use_existing_browser.clone()),
+        ]
+    }
+
+    fn diff_properties(&self, other: &VB6Project<'a>, changes: &mut Vec<VB6ProjectChange<'a>>) {
+        let properties = self.properties().into_iter().zip(other.properties());
+
+        changes.extend(
+            properties
+                .filter(|(old, new)| old != new)
+                .map(|(old, new)| VB6ProjectChange::PropertyChanged { old, new }),
+        );
     }
+
+    fn diff_other_properties(
+        &self,
+        other: &VB6Project<'a>,
+        changes: &mut Vec<VB6ProjectChange<'a>>,
+    ) {
+        let mut sections = self
+            .other_properties
+            .keys()
+            .chain(other.other_properties.keys())
+            .copied()
+            .collect::<Vec<_>>();
+        sections.sort();
+        sections.dedup();
+
+        for section in sections {
+            let old_section = self.other_properties.get(section);
+            let new_section = other.other_properties.get(section);
+
+            let mut names = old_section
+                .into_iter()
+                .chain(new_section)
+                .flat_map(HashMap::keys)
+                .copied()
+                .collect::<Vec<_>>();
+            names.sort();
+            names.dedup();
+
+            for name in names {
+                let old = old_section.and_then(|properties| properties.get(name).copied());
+                let new = new_section.and_then(|properties| properties.get(name).copied());
+
+                if old != new {
+                    changes.push(VB6ProjectChange::OtherPropertyChanged {
+                        section,
+                        name,
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn reference_key<'a>(reference: &VB6ProjectReference<'a>) -> Either<Uuid, &'a BStr> {
+    match reference {
+        VB6ProjectReference::Compiled { uuid, .. } => Either::Left(*uuid),
+        VB6ProjectReference::SubProject { path } => Either::Right(path),
+    }
+}
+
+fn object_key<'a>(object: &VB6ObjectReference<'a>) -> Either<Uuid, &'a BStr> {
+    match object {
+        VB6ObjectReference::Compiled { uuid, .. } => Either::Left(*uuid),
+        VB6ObjectReference::Project { path } => Either::Right(path),
+    }
+}
+
+/// The key members are matched by. VB6 paths are not case sensitive.
+fn member_key(path: &BStr) -> Vec<u8> {
+    path.to_ascii_lowercase()
+}
+
+fn diff_by_key<'b, T, K, F>(old: &'b [T], new: &'b [T], key: F) -> (Vec<&'b T>, Vec<&'b T>)
+where
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    let added = new
+        .iter()
+        .filter(|item| !old.iter().any(|old_item| key(old_item) == key(item)))
+        .collect();
+
+    let removed = old
+        .iter()
+        .filter(|item| !new.iter().any(|new_item| key(new_item) == key(item)))
+        .collect();
+
+    (added, removed)
+}
+
+fn changed_by_key<'b, T, K, F>(old: &'b [T], new: &'b [T], key: F) -> Vec<(&'b T, &'b T)>
+where
+    T: PartialEq,
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    old.iter()
+        .filter_map(|old_item| {
+            new.iter()
+                .find(|new_item| key(new_item) == key(old_item))
+                .filter(|new_item| *new_item != old_item)
+                .map(|new_item| (old_item, new_item))
+        })
+        .collect()
 }
 
 fn process_parameter<T>(
@@ -1765,12 +3039,19 @@ fn project_type_parse(input: &mut VB6Stream<'_>) -> VB6Result<CompileTargetType>
     //
     // By this point in the parse the "Type=" component should be stripped off
     // since that is how we knew to use this particular parse.
+    //
+    // The IDE never writes the display names ('Standard EXE', 'ActiveX DLL',
+    // etc.) but they turn up in hand edited files, so they are accepted too.
 
     let Ok(project_type) = alt::<_, CompileTargetType, VB6ErrorKind, _>((
         "Exe".value(CompileTargetType::Exe),
         "Control".value(CompileTargetType::Control),
         "OleExe".value(CompileTargetType::OleExe),
         "OleDll".value(CompileTargetType::OleDll),
+        Caseless("Standard EXE").value(CompileTargetType::Exe),
+        Caseless("ActiveX Control").value(CompileTargetType::Control),
+        Caseless("ActiveX EXE").value(CompileTargetType::OleExe),
+        Caseless("ActiveX DLL").value(CompileTargetType::OleDll),
     ))
     .parse_next(input) else {
         return Err(ErrMode::Cut(VB6ErrorKind::ProjectTypeUnknown));
@@ -1881,6 +3162,24 @@ mod tests {
         assert_eq!(result, CompileTargetType::OleExe);
     }
 
+    #[test]
+    fn project_type_display_names() {
+        for (line, expected) in [
+            ("Type=Standard EXE\r\n", CompileTargetType::Exe),
+            ("Type=ActiveX DLL\r\n", CompileTargetType::OleDll),
+            ("Type=ActiveX EXE\r\n", CompileTargetType::OleExe),
+            ("Type=ActiveX Control\r\n", CompileTargetType::Control),
+        ] {
+            let mut input = VB6Stream::new("", line.as_bytes());
+
+            let _: Result<&BStr, ErrMode<VB6ErrorKind>> = "Type".parse_next(&mut input);
+
+            let result = project_type_parse.parse_next(&mut input).unwrap();
+
+            assert_eq!(result, expected, "{line}");
+        }
+    }
+
     #[test]
     fn project_type_is_unknown_type() {
         let mut input = VB6Stream::new("", b"Type=blah\r\n");
@@ -2107,6 +3406,173 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unknown_line_suppressed() {
+        use crate::parsers::ParseOptions;
+
+        let input = b"Type=Exe\r\nToolVersion=\"1.0\"\r\nName=\"Project1\"\r\n";
+
+        let result = VB6Project::parse("project1.vbp", input);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind, VB6ErrorKind::LineTypeUnknown);
+
+        let mut options = ParseOptions::default();
+        options
+            .suppress
+            .insert(VB6ErrorKind::LineTypeUnknown.code());
+
+        let project = VB6Project::parse_with_options("project1.vbp", input, &options).unwrap();
+
+        assert_eq!(project.project_type, CompileTargetType::Exe);
+        assert_eq!(project.name, Some(BStr::new(b"Project1")));
+    }
+
+    #[test]
+    fn lookup_by_uuid() {
+        let input = b"Type=Exe\r
+Reference=*\\G{00020430-0000-0000-c000-000000000046}#2.0#0#C:\\Windows\\System32\\stdole2.tlb#OLE Automation\r
+Object={00020430-0000-0000-C000-000000000046}#2.0#0; stdole2.tlb\r
+";
+
+        let project = VB6Project::parse("project1.vbp", input).unwrap();
+
+        let present = Uuid::parse_str("00020430-0000-0000-C000-000000000046").unwrap();
+        let absent = Uuid::parse_str("831FDD16-0C5C-11D2-A9FC-0000F8754DA1").unwrap();
+
+        assert!(matches!(
+            project.reference_by_uuid(&present),
+            Some(VB6ProjectReference::Compiled { description, .. }) if *description == "OLE Automation"
+        ));
+        assert!(matches!(
+            project.object_by_uuid(&present),
+            Some(VB6ObjectReference::Compiled { file_name, .. }) if *file_name == "stdole2.tlb"
+        ));
+        assert_eq!(project.reference_by_uuid(&absent), None);
+        assert_eq!(project.object_by_uuid(&absent), None);
+    }
+
+    #[test]
+    fn unknown_line_column_with_tab_width() {
+        use crate::parsers::ParseOptions;
+
+        let input = b"Type=Exe\r\n\tToolVersion=1\r\n";
+
+        let error = VB6Project::parse("project1.vbp", input).unwrap_err();
+
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.column, 2);
+
+        let options = ParseOptions {
+            tab_width: 4,
+            ..ParseOptions::default()
+        };
+
+        let error = VB6Project::parse_with_options("project1.vbp", input, &options).unwrap_err();
+
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.column, 5);
+    }
+
+    #[test]
+    fn diff_added_form_and_changed_title() {
+        let old = b"Type=Exe\r
+Form=Form1.frm\r
+Module=Module1; Module1.bas\r
+Class=Class1; Class1.cls\r
+Title=\"Project1\"\r
+";
+        let new = b"Type=Exe\r
+Class=Class1; Class1.cls\r
+Module=Module1; Module1.bas\r
+Form=Form1.frm\r
+Form=Form2.frm\r
+Title=\"Project2\"\r
+";
+
+        let old = VB6Project::parse("old.vbp", old).unwrap();
+        let new = VB6Project::parse("new.vbp", new).unwrap();
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.changes.len(), 2);
+        assert_eq!(
+            diff.changes[0],
+            VB6ProjectChange::MemberAdded {
+                kind: VB6ProjectMemberKind::Form,
+                path: BStr::new(b"Form2.frm"),
+            }
+        );
+        assert_eq!(
+            diff.changes[1],
+            VB6ProjectChange::PropertyChanged {
+                old: VB6ProjectProperty::Title(Some(BStr::new(b"Project1"))),
+                new: VB6ProjectProperty::Title(Some(BStr::new(b"Project2"))),
+            }
+        );
+    }
+
+    #[test]
+    fn diff_ignores_case_of_member_paths() {
+        let old = b"Type=Exe\r
+Form=Form1.frm\r
+Module=Module1; Module1.bas\r
+";
+        let new = b"Type=Exe\r
+Form=FORM1.FRM\r
+Module=Module1; module1.bas\r
+";
+
+        let old = VB6Project::parse("old.vbp", old).unwrap();
+        let new = VB6Project::parse("new.vbp", new).unwrap();
+
+        assert!(old.diff(&new).is_empty());
+        assert!(old.semantically_eq(&new));
+    }
+
+    #[test]
+    fn diff_renamed_module_and_compilation_type() {
+        let old = b"Type=Exe\r
+Module=Module1; Module1.bas\r
+Class=Class1; Class1.cls\r
+CompilationType=-1\r
+";
+        let new = b"Type=Exe\r
+Module=Helpers; Module1.bas\r
+Class=Class1; Class1.cls\r
+CompilationType=0\r
+";
+
+        let old = VB6Project::parse("old.vbp", old).unwrap();
+        let new = VB6Project::parse("new.vbp", new).unwrap();
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.changes.len(), 2);
+        assert_eq!(
+            diff.changes[0],
+            VB6ProjectChange::MemberRenamed {
+                kind: VB6ProjectMemberKind::Module,
+                path: BStr::new(b"Module1.bas"),
+                old: BStr::new(b"Module1"),
+                new: BStr::new(b"Helpers"),
+            }
+        );
+
+        let VB6ProjectChange::PropertyChanged { old, new } = &diff.changes[1] else {
+            panic!("Expected a property change.");
+        };
+        assert_eq!(old.name(), "compilation_type");
+        assert_eq!(
+            *old,
+            VB6ProjectProperty::CompilationType(CompilationType::PCode)
+        );
+        assert!(matches!(
+            new,
+            VB6ProjectProperty::CompilationType(CompilationType::NativeCode { .. })
+        ));
+    }
+
     #[test]
     fn no_startup_selected() {
         use bstr::BStr;
@@ -2388,4 +3854,338 @@ mod tests {
             "debug_startup_option check"
         );
     }
+
+    #[test]
+    fn mts_and_compiler_settings() {
+        let input = r#"Type=OleDll
+Reference=*\G{00020430-0000-0000-C000-000000000046}#2.0#0#..\..\..\..\..\..\..\Windows\SysWOW64\stdole2.tlb#OLE Automation
+Class=Class1; Class1.cls
+Name="Project1"
+
+[MS Transaction Server]
+AutoRefresh=1
+
+[VBCompiler]
+LinkSwitches=/STACK:32180000
+"#;
+
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(
+            project.mts_settings(),
+            Some(VB6MtsSettings { auto_refresh: true })
+        );
+        assert_eq!(
+            project.compiler_settings(),
+            Some(VB6CompilerSettings {
+                link_switches: Some(BStr::new("/STACK:32180000")),
+                comment: None,
+            })
+        );
+        assert_eq!(
+            project.other_properties[BStr::new("MS Transaction Server")][BStr::new("AutoRefresh")],
+            "1"
+        );
+
+        let input = "Type=Exe\nName=\"Project1\"\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(project.mts_settings(), None);
+        assert_eq!(project.compiler_settings(), None);
+    }
+
+    #[test]
+    fn summary_counts() {
+        let input = r#"Type=Exe
+Reference=*\G{00020430-0000-0000-C000-000000000046}#2.0#0#C:\Windows\System32\stdole2.tlb#OLE Automation
+Reference=*\Aproject1.vbp
+Object={00020430-0000-0000-C000-000000000046}#2.0#0; stdole2.tlb
+Module=Module1; Module1.bas
+Class=Class1; Class1.cls
+Form=Form1.frm
+Form=Form2.frm
+UserControl=UserControl1.ctl
+UserDocument=UserDocument1.uds
+RelatedDoc=Notes.txt
+PropertyPage=PropertyPage1.pag
+PropertyPage=PropertyPage2.pag
+Startup="Form1"
+Title="Project1"
+
+[MS Transaction Server]
+AutoRefresh=1
+"#;
+
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(
+            project.summary(),
+            VB6ProjectSummary {
+                compiled_references: 1,
+                subproject_references: 1,
+                objects: 1,
+                modules: 1,
+                classes: 1,
+                forms: 2,
+                user_controls: 1,
+                user_documents: 1,
+                designers: 0,
+                related_documents: 1,
+                property_pages: 2,
+                other_sections: 1,
+            }
+        );
+        assert_eq!(project.member_count(), 9);
+
+        #[allow(deprecated)]
+        let first_property_page = project.property_page();
+        assert_eq!(first_property_page, Some(BStr::new("PropertyPage1.pag")));
+    }
+
+    #[test]
+    fn conditional_compile_constants() {
+        let input = "Type=Exe\r\nCondComp=\"A = 1 : B = 0\"\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(
+            project.conditional_compile_constants(),
+            vec![
+                (BStr::new("A"), BStr::new("1")),
+                (BStr::new("B"), BStr::new("0"))
+            ]
+        );
+
+        let input = "Type=Exe\r\nCondComp=\"\"\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert!(project.conditional_compile_constants().is_empty());
+    }
+
+    #[test]
+    fn duplicate_members() {
+        let input = r#"Type=Exe
+Module=Module1; Module1.bas
+Form=Form1.frm
+Form=Form2.frm
+Form=Form1.frm
+UserControl=Form1.frm
+"#;
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(project.forms.len(), 3);
+        assert_eq!(
+            project.duplicate_members(),
+            vec![VB6DuplicateMember {
+                kind: VB6ProjectMemberKind::Form,
+                path: BStr::new("Form1.frm"),
+            }]
+        );
+    }
+
+    #[test]
+    fn duplicate_members_differing_in_case_are_not_a_change() {
+        let old = VB6Project::parse("old.vbp", b"Type=Exe\r\nForm=Form1.frm\r\n").unwrap();
+        let new = VB6Project::parse(
+            "new.vbp",
+            b"Type=Exe\r\nForm=Form1.frm\r\nForm=FORM1.FRM\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            new.duplicate_members(),
+            vec![VB6DuplicateMember {
+                kind: VB6ProjectMemberKind::Form,
+                path: BStr::new("FORM1.FRM"),
+            }]
+        );
+        assert!(old.diff(&new).is_empty());
+        assert!(new.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn iterate_references() {
+        let input = r#"Type=Exe
+Reference=*\G{00020430-0000-0000-C000-000000000046}#2.0#0#C:\Windows\System32\stdole2.tlb#OLE Automation
+Reference=*\Aproject1.vbp
+"#;
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        let mut references = vec![];
+        for reference in &project {
+            references.push(reference);
+        }
+
+        assert_eq!(references.len(), 2);
+        assert!(matches!(
+            references[0],
+            VB6ProjectReference::Compiled { .. }
+        ));
+        assert!(matches!(
+            references[1],
+            VB6ProjectReference::SubProject { .. }
+        ));
+    }
+
+    #[test]
+    fn startup_object() {
+        let input = "Type=Exe\r\nForm=frmMain.frm\r\nStartup=\"FRMMAIN\"\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(
+            project.startup_object(),
+            VB6StartupObject::Form(BStr::new("FRMMAIN"))
+        );
+
+        let input = "Type=Exe\r\nForm=frmMain.frm\r\nStartup=\"frmOther\"\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(
+            project.startup_object(),
+            VB6StartupObject::Unknown(BStr::new("frmOther"))
+        );
+
+        let input = "Type=Exe\r\nModule=Module1; Module1.bas\r\nStartup=\"Sub Main\"\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(project.startup_object(), VB6StartupObject::SubMain);
+
+        let input = "Type=OleDll\r\nClass=Class1; Class1.cls\r\nStartup=!(None)!\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(project.startup_object(), VB6StartupObject::None);
+
+        let input = "Type=Exe\r\nForm=frmMain.frm\r\n";
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert_eq!(project.startup_object(), VB6StartupObject::None);
+
+        let project = VB6ProjectBuilder::new(CompileTargetType::Exe)
+            .form("frmMain.frm")
+            .build();
+
+        assert_eq!(project.startup_object(), VB6StartupObject::None);
+    }
+
+    #[test]
+    fn builder_exe_with_one_form() {
+        let project = VB6ProjectBuilder::new(CompileTargetType::Exe)
+            .form("Form1.frm")
+            .startup("Form1")
+            .exe_32_file_name("Project1.exe")
+            .build();
+
+        assert_eq!(project.project_type, CompileTargetType::Exe);
+        assert_eq!(project.forms, vec![BStr::new("Form1.frm")]);
+        assert_eq!(project.startup, Some(BStr::new("Form1")));
+        assert_eq!(project.name, Some(BStr::new("Project1")));
+        assert_eq!(project.title, Some(BStr::new("Project1")));
+        assert_eq!(project.exe_32_file_name, Some(BStr::new("Project1.exe")));
+        assert_eq!(project.version_info.major, 1);
+        assert_eq!(project.compilation_type, CompilationType::PCode);
+        assert!(project.references.is_empty());
+        assert!(project.modules.is_empty());
+
+        let input = r#"Type=Exe
+Form=Form1.frm
+Startup="Form1"
+ExeName32="Project1.exe"
+Title="Project1"
+HelpContextID="0"
+MajorVer=1
+Name="Project1"
+ThreadPerObject=0
+"#;
+        let parsed = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+
+        assert!(
+            parsed.diff(&project).is_empty(),
+            "{:?}",
+            parsed.diff(&project)
+        );
+    }
+
+    #[test]
+    fn subproject_build_order_acyclic() {
+        let app = VB6Project::parse(
+            "app.vbp",
+            b"Type=Exe\nReference=*\\Acore.vbp\nReference=*\\Aui.vbp\n",
+        )
+        .unwrap();
+        let ui = VB6Project::parse("ui.vbp", b"Type=OleDll\nReference=*\\Acore.vbp\n").unwrap();
+        let core = VB6Project::parse("core.vbp", b"Type=OleDll\n").unwrap();
+
+        let projects = HashMap::from([("app.vbp", &app), ("ui.vbp", &ui), ("core.vbp", &core)]);
+
+        let order = subproject_build_order("app.vbp", |path| projects.get(path).copied()).unwrap();
+
+        assert_eq!(order, vec!["core.vbp", "ui.vbp", "app.vbp"]);
+    }
+
+    #[test]
+    fn subproject_build_order_cycle() {
+        let a = VB6Project::parse("a.vbp", b"Type=Exe\nReference=*\\Ab.vbp\n").unwrap();
+        let b = VB6Project::parse("b.vbp", b"Type=OleDll\nReference=*\\Ac.vbp\n").unwrap();
+        let c = VB6Project::parse("c.vbp", b"Type=OleDll\nReference=*\\Aa.vbp\n").unwrap();
+
+        let projects = HashMap::from([("a.vbp", &a), ("b.vbp", &b), ("c.vbp", &c)]);
+
+        let error =
+            subproject_build_order("a.vbp", |path| projects.get(path).copied()).unwrap_err();
+
+        assert_eq!(error.cycle, vec!["a.vbp", "b.vbp", "c.vbp", "a.vbp"]);
+        assert_eq!(
+            error.to_string(),
+            "Circular sub-project reference: a.vbp -> b.vbp -> c.vbp -> a.vbp"
+        );
+    }
+
+    #[test]
+    fn semantically_eq_ignores_order() {
+        let first = r#"Type=Exe
+Reference=*\G{00020430-0000-0000-C000-000000000046}#2.0#0#..\..\Windows\SysWOW64\stdole2.tlb#OLE Automation
+Module=Module1; Module1.bas
+Module=Module2; Module2.bas
+Form=Form1.frm
+Form=Form2.frm
+Name="Project1"
+"#;
+        let second = r#"Type=Exe
+Form=Form2.frm
+Form=Form1.frm
+Module=Module2; Module2.bas
+Module=Module1; Module1.bas
+Reference=*\G{00020430-0000-0000-C000-000000000046}#2.0#0#..\..\Windows\SysWOW64\stdole2.tlb#OLE Automation
+Name="Project1"
+"#;
+        let renamed = second.replace("Module=Module2;", "Module=Helpers;");
+
+        let first = VB6Project::parse("first.vbp", first.as_bytes()).unwrap();
+        let second = VB6Project::parse("second.vbp", second.as_bytes()).unwrap();
+        let renamed = VB6Project::parse("renamed.vbp", renamed.as_bytes()).unwrap();
+
+        assert_ne!(first, second);
+        assert!(first.semantically_eq(&second));
+        assert!(second.semantically_eq(&first));
+        assert!(!first.semantically_eq(&renamed));
+    }
+
+    #[test]
+    fn project_reference_round_trip() {
+        let input = r#"Type=Exe
+Reference=*\G{00020430-0000-0000-C000-000000000046}#2.0#0#..\..\Windows\SysWOW64\stdole2.tlb#OLE Automation
+Reference=*\A..\Library\Library.vbp
+"#;
+
+        let project = VB6Project::parse("project1.vbp", input.as_bytes()).unwrap();
+        assert_eq!(project.references.len(), 2);
+
+        for reference in &project.references {
+            let json = serde_json::to_string(reference).unwrap();
+            let owned: VB6ProjectReferenceOwned = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(owned, VB6ProjectReferenceOwned::from(reference));
+            assert_eq!(&owned.as_reference(), reference);
+            assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use serde::Serialize;
+
+use crate::{
+    errors::VB6Error,
+    language::{VB6Control, VB6Token},
+    parsers::{
+        designer::{designer_file_parse, DesignerKind},
+        header::{VB6FileAttributes, VB6FileFormatVersion},
+        VB6ObjectReference,
+    },
+};
+
+/// Represents a VB6 `PropertyPage` file.
+///
+/// A property page file (`.pag`) is laid out like a form file. The root
+/// control is a `VB.PropertyPage` instead of a `VB.Form`, and it holds the
+/// controls shown on the page. Any binary data lives in a matching `.pgx`
+/// file which is not read here.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct VB6PropertyPageFile<'a> {
+    pub property_page: VB6Control<'a>,
+    pub objects: Vec<VB6ObjectReference<'a>>,
+    pub format_version: VB6FileFormatVersion,
+    pub attributes: VB6FileAttributes<'a>,
+    pub tokens: Vec<VB6Token<'a>>,
+}
+
+impl<'a> VB6PropertyPageFile<'a> {
+    /// Parses a VB6 property page file from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` The byte slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the parsed VB6 property page file or an error.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the input is not a valid VB6 property page
+    /// file, including when the root control is not a `VB.PropertyPage`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6PropertyPageFile;
+    ///
+    /// let input = b"VERSION 5.00\r
+    /// Begin VB.PropertyPage ppgExample\r
+    ///    ClientHeight    =   3600\r
+    ///    ClientWidth     =   4800\r
+    ///    ScaleHeight     =   3600\r
+    ///    ScaleWidth      =   4800\r
+    /// End\r
+    /// Attribute VB_Name = \"ppgExample\"\r
+    /// ";
+    ///
+    /// let result = VB6PropertyPageFile::parse("ppgExample.pag".to_owned(), input);
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn parse(file_name: String, input: &'a [u8]) -> Result<Self, VB6Error> {
+        let designer = designer_file_parse(file_name, input, DesignerKind::PropertyPage)?;
+
+        Ok(VB6PropertyPageFile {
+            property_page: designer.root,
+            objects: designer.objects,
+            format_version: designer.format_version,
+            attributes: designer.attributes,
+            tokens: designer.tokens,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bstr::BStr;
+
+    use crate::language::VB6ControlKind;
+
+    #[test]
+    fn property_page_with_label() {
+        let input = b"VERSION 5.00\r
+Begin VB.PropertyPage ppgGeneral\r
+   Caption         =   \"General\"\r
+   ClientHeight    =   3600\r
+   ClientWidth     =   4800\r
+   ScaleHeight     =   3600\r
+   ScaleWidth      =   4800\r
+   Begin VB.Label lblCaption\r
+      Caption         =   \"Caption:\"\r
+      Height          =   240\r
+      Left            =   90\r
+      TabIndex        =   0\r
+      Top             =   90\r
+      Width           =   2700\r
+   End\r
+End\r
+Attribute VB_Name = \"ppgGeneral\"\r
+Private Sub PropertyPage_ApplyChanges()\r
+End Sub\r
+";
+
+        let property_page = VB6PropertyPageFile::parse("ppgGeneral.pag".to_owned(), input).unwrap();
+
+        assert_eq!(property_page.property_page.name, "ppgGeneral");
+        assert_eq!(property_page.attributes.name, "ppgGeneral");
+
+        match &property_page.property_page.kind {
+            VB6ControlKind::PropertyPage {
+                properties,
+                controls,
+                ..
+            } => {
+                assert_eq!(
+                    properties.get(BStr::new("Caption")),
+                    Some(&BStr::new("General"))
+                );
+                assert_eq!(controls.len(), 1);
+                assert_eq!(controls[0].name, "lblCaption");
+                assert!(matches!(controls[0].kind, VB6ControlKind::Label { .. }));
+            }
+            _ => panic!("Expected a property page."),
+        }
+
+        assert!(property_page
+            .tokens
+            .iter()
+            .any(|token| *token == VB6Token::VariableName("PropertyPage_ApplyChanges".into())));
+    }
+}
@@ -0,0 +1,143 @@
+use serde::Serialize;
+
+use crate::{
+    errors::VB6Error,
+    language::{VB6Control, VB6Token},
+    parsers::{
+        designer::{designer_file_parse, DesignerKind},
+        header::{VB6FileAttributes, VB6FileFormatVersion},
+        VB6ObjectReference,
+    },
+};
+
+/// Represents a VB6 `UserControl` file.
+///
+/// A user control file (`.ctl`) is laid out like a form file. The root
+/// control is a `VB.UserControl` instead of a `VB.Form`, and it holds the
+/// constituent controls that make up the user control.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct VB6UserControlFile<'a> {
+    pub user_control: VB6Control<'a>,
+    pub objects: Vec<VB6ObjectReference<'a>>,
+    pub format_version: VB6FileFormatVersion,
+    pub attributes: VB6FileAttributes<'a>,
+    pub tokens: Vec<VB6Token<'a>>,
+}
+
+impl<'a> VB6UserControlFile<'a> {
+    /// Parses a VB6 user control file from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` The byte slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the parsed VB6 user control file or an error.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the input is not a valid VB6 user control
+    /// file, including when the root control is not a `VB.UserControl`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6UserControlFile;
+    ///
+    /// let input = b"VERSION 5.00\r
+    /// Begin VB.UserControl ctlExample\r
+    ///    ClientHeight    =   3600\r
+    ///    ClientWidth     =   4800\r
+    ///    ScaleHeight     =   3600\r
+    ///    ScaleWidth      =   4800\r
+    /// End\r
+    /// Attribute VB_Name = \"ctlExample\"\r
+    /// ";
+    ///
+    /// let result = VB6UserControlFile::parse("ctlExample.ctl".to_owned(), input);
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn parse(file_name: String, input: &'a [u8]) -> Result<Self, VB6Error> {
+        let designer = designer_file_parse(file_name, input, DesignerKind::UserControl)?;
+
+        Ok(VB6UserControlFile {
+            user_control: designer.root,
+            objects: designer.objects,
+            format_version: designer.format_version,
+            attributes: designer.attributes,
+            tokens: designer.tokens,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bstr::BStr;
+
+    use crate::language::VB6ControlKind;
+
+    #[test]
+    fn user_control_with_button() {
+        let input = b"VERSION 5.00\r
+Begin VB.UserControl ctlCounter\r
+   ClientHeight    =   1200\r
+   ClientLeft      =   0\r
+   ClientTop       =   0\r
+   ClientWidth     =   2400\r
+   ScaleHeight     =   1200\r
+   ScaleWidth      =   2400\r
+   Begin VB.CommandButton cmdIncrement\r
+      Caption         =   \"+\"\r
+      Height          =   375\r
+      Left            =   120\r
+      TabIndex        =   0\r
+      Top             =   120\r
+      Width           =   615\r
+   End\r
+End\r
+Attribute VB_Name = \"ctlCounter\"\r
+Attribute VB_GlobalNameSpace = False\r
+Attribute VB_Creatable = True\r
+Attribute VB_PredeclaredId = False\r
+Attribute VB_Exposed = False\r
+Option Explicit\r
+\r
+Private Sub cmdIncrement_Click()\r
+End Sub\r
+";
+
+        let user_control = VB6UserControlFile::parse("ctlCounter.ctl".to_owned(), input).unwrap();
+
+        assert_eq!(user_control.user_control.name, "ctlCounter");
+        assert_eq!(user_control.attributes.name, "ctlCounter");
+
+        match &user_control.user_control.kind {
+            VB6ControlKind::UserControl {
+                properties,
+                controls,
+                ..
+            } => {
+                assert_eq!(
+                    properties.get(BStr::new("ScaleWidth")),
+                    Some(&BStr::new("2400"))
+                );
+                assert_eq!(controls.len(), 1);
+                assert_eq!(controls[0].name, "cmdIncrement");
+                assert!(matches!(
+                    controls[0].kind,
+                    VB6ControlKind::CommandButton { .. }
+                ));
+            }
+            _ => panic!("Expected a user control."),
+        }
+
+        assert!(user_control
+            .tokens
+            .iter()
+            .any(|token| *token == VB6Token::VariableName("cmdIncrement_Click".into())));
+    }
+}
@@ -1,15 +1,21 @@
-use bstr::{BStr, ByteSlice};
+use std::ops::Range;
+
+use bstr::{BStr, BString, ByteSlice};
 
 use winnow::{
-    ascii::{digit1, line_ending, space1, Caseless},
-    combinator::{alt, delimited, repeat},
+    ascii::{digit1, hex_digit1, line_ending, oct_digit1, space1, Caseless},
+    combinator::{alt, delimited, not, opt, repeat, terminated},
     error::ErrMode,
     stream::Stream,
     token::{literal, one_of, take_till, take_until, take_while},
     Parser,
 };
 
-use crate::{errors::VB6ErrorKind, language::VB6Token, parsers::VB6Stream};
+use crate::{
+    errors::VB6ErrorKind,
+    language::{VB6Comment, VB6CommentKind, VB6Token},
+    parsers::VB6Stream,
+};
 
 pub type VB6Result<T> = Result<T, ErrMode<VB6ErrorKind>>;
 
@@ -54,6 +60,77 @@ pub fn line_comment_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr>
     Ok(comment)
 }
 
+/// Parses a VB6 `Rem` comment.
+///
+/// A `Rem` comment starts with the `Rem` keyword and runs to the end of the
+/// line. `Rem` must be a whole word, so an identifier such as `RemCount`
+/// is not the start of a comment. The carriage return and newline are not
+/// consumed.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// Will return an error if the input does not start with the `Rem` keyword.
+///
+/// # Returns
+///
+/// The comment including the `Rem` keyword, but without carriage return and
+/// newline characters.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::{vb6::rem_comment_parse, VB6Stream};
+///
+/// let mut input = VB6Stream::new("rem_comment.bas", "Rem This is a comment\r\n".as_bytes());
+/// let comment = rem_comment_parse(&mut input).unwrap();
+///
+/// assert_eq!(comment, "Rem This is a comment");
+/// ```
+pub fn rem_comment_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr> {
+    (
+        keyword_parse("Rem"),
+        take_till(0.., (b"\r\n", b"\n", b"\r")),
+    )
+        .take()
+        .parse_next(input)
+}
+
+/// Parses a single line ending.
+///
+/// A line ending is a carriage return followed by a newline, a newline on
+/// its own, or a carriage return on its own.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// If the input does not start with a line ending, it will return an error.
+///
+/// # Returns
+///
+/// The line ending exactly as it appears in the input, so it can be written
+/// back out unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::{vb6::newline_parse, VB6Stream};
+///
+/// let mut input = VB6Stream::new("newline.bas", "\r\nDim x".as_bytes());
+/// let newline = newline_parse(&mut input).unwrap();
+///
+/// assert_eq!(newline, "\r\n");
+/// ```
+pub fn newline_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr> {
+    alt(("\r\n", "\n", "\r")).parse_next(input)
+}
+
 /// Parses a VB6 variable name.
 ///
 /// The variable name starts with a letter and can contain letters, numbers, and underscores.
@@ -95,10 +172,192 @@ pub fn variable_name_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr>
     Ok(variable_name)
 }
 
+/// Parses a VB6 variable name along with its type-declaration character.
+///
+/// The type-declaration character is optional and is one of `$` (String),
+/// `%` (Integer), `&` (Long), `!` (Single), `#` (Double), or `@` (Currency).
+/// The character is only treated as part of the name when it is not directly
+/// followed by another identifier character. This keeps `rs!Field` (bang
+/// access) and `x&H10` (a name followed by a hex value) from being misread as
+/// typed names.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// If the variable name is too long, it will return an error.
+///
+/// # Returns
+///
+/// The VB6 variable name including the type-declaration character if present.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::{vb6::typed_variable_name_parse, VB6Stream};
+///
+/// let mut input = VB6Stream::new("typed_name_test.bas", "name$ = \"a\"".as_bytes());
+/// let variable_name = typed_variable_name_parse(&mut input).unwrap();
+///
+/// assert_eq!(variable_name, "name$");
+/// ```
+pub fn typed_variable_name_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr> {
+    (
+        variable_name_parse,
+        opt(terminated(
+            one_of(('$', '%', '&', '!', '#', '@')),
+            not(one_of(('_', 'a'..='z', 'A'..='Z', '0'..='9', 128..=255))),
+        )),
+    )
+        .take()
+        .parse_next(input)
+}
+
+/// Parses a VB6 number literal.
+///
+/// This covers integers, floating point numbers with an optional leading
+/// dot or exponent (`.5`, `1.5E-3`, `2D10`), and a trailing type-declaration
+/// character (`1.5!`, `1.5#`, `10&`). Hex and octal values (`&H00FF&`,
+/// `&O17`) are numbers too, and may only end in `%` or `&`.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::VB6Stream;
+/// use vb6parse::parsers::vb6::number_parse;
+///
+/// let mut input = VB6Stream::new("", b"1.5E-3# + 1");
+/// let number = number_parse(&mut input).unwrap();
+///
+/// assert_eq!(number, "1.5E-3#");
+/// ```
+///
+/// # Errors
+///
+/// Will return an error if the input does not start with a number.
+pub fn number_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr> {
+    alt((
+        (
+            '&',
+            alt((
+                (one_of(('h', 'H')), hex_digit1).void(),
+                (one_of(('o', 'O')), oct_digit1).void(),
+            )),
+            opt(terminated(
+                one_of(('%', '&')),
+                not(one_of(('_', 'a'..='z', 'A'..='Z', '0'..='9', 128..=255))),
+            )),
+            // '&Hello' is '&' followed by a name, not a hex value.
+            not(one_of(('_', 'a'..='z', 'A'..='Z', '0'..='9', 128..=255))),
+        )
+            .take(),
+        (
+            alt(((digit1, opt(('.', digit1))).void(), ('.', digit1).void())),
+            opt((
+                one_of(('e', 'E', 'd', 'D')),
+                opt(one_of(('+', '-'))),
+                digit1,
+            )),
+            opt(terminated(
+                one_of(('%', '&', '!', '#', '@')),
+                not(one_of(('_', 'a'..='z', 'A'..='Z', '0'..='9', 128..=255))),
+            )),
+        )
+            .take(),
+    ))
+    .parse_next(input)
+}
+
+/// Parses a VB6 date literal such as `#1/1/2000#` or `#12:30:00 PM#`.
+///
+/// Only the numeric forms of dates and times are recognized, which keeps
+/// file numbers like `#1` and directives like `#If` from being mistaken for
+/// dates.
+///
+/// # Errors
+///
+/// Will return an error if the input does not start with a date literal.
+pub fn date_literal_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr> {
+    let checkpoint = input.checkpoint();
+
+    let date = (
+        '#',
+        digit1,
+        take_while(0.., |c: u8| {
+            c.is_ascii_digit() || b"/-:. AMPamp".contains(&c)
+        }),
+        '#',
+    )
+        .take()
+        .parse_next(input);
+
+    if date.is_err() {
+        input.reset(&checkpoint);
+    }
+
+    date
+}
+
 pub fn take_until_line_ending<'a>(input: &mut VB6Stream<'a>) -> VB6Result<&'a BStr> {
     alt((take_until(1.., "\r\n"), take_until(1.., "\n"))).parse_next(input)
 }
 
+/// Takes input until the first of several terminators.
+///
+/// Unlike trying each terminator in turn, this stops at whichever terminator
+/// appears earliest in the input. When two terminators start at the same
+/// place, the one listed first wins. The terminator itself is not consumed.
+///
+/// # Arguments
+///
+/// * `terminators` - The terminators to search for.
+///
+/// # Errors
+///
+/// If none of the terminators are found, it will return an error and the
+/// input will not be consumed.
+///
+/// # Returns
+///
+/// The input before the terminator, and the terminator that was matched.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::{vb6::take_until_any, VB6Stream};
+///
+/// let mut input = VB6Stream::new("test.vbp", b"stdole2.tlb#OLE Automation; other".as_slice());
+/// let mut until_separator = take_until_any(&["; ", "#"]);
+///
+/// let (taken, terminator) = until_separator(&mut input).unwrap();
+///
+/// assert_eq!(taken, "stdole2.tlb");
+/// assert_eq!(terminator, "#");
+/// ```
+pub fn take_until_any<'a>(
+    terminators: &'static [&'static str],
+) -> impl FnMut(&mut VB6Stream<'a>) -> VB6Result<(&'a BStr, &'static str)> {
+    move |input: &mut VB6Stream<'a>| -> VB6Result<(&'a BStr, &'static str)> {
+        let remaining = &input.stream[input.index..];
+
+        let Some((offset, terminator)) = terminators
+            .iter()
+            .filter_map(|terminator| {
+                remaining
+                    .find(terminator)
+                    .map(|offset| (offset, *terminator))
+            })
+            .min_by_key(|(offset, _)| *offset)
+        else {
+            return Err(ErrMode::Backtrack(VB6ErrorKind::TerminatorNotFound));
+        };
+
+        Ok((input.next_slice(offset), terminator))
+    }
+}
+
 /// Parses a VB6 keyword.
 ///
 /// The keyword is case-insensitive.
@@ -266,49 +525,204 @@ pub fn vb6_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<Vec<VB6Token<'a>>>
         return Err(ErrMode::Cut(VB6ErrorKind::LikelyNonEnglishCharacterSet));
     }
 
-    while !input.is_empty() {
-        // The file should end if there is a null byte.
-        if literal::<_, _, VB6ErrorKind>('\0')
-            .parse_next(input)
-            .is_ok()
-        {
+    while let Some(token) = vb6_next_token(input)? {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Parses VB6 code into tokens along with the byte range each token covers
+/// in the source.
+///
+/// The ranges are contiguous, so together they cover everything up to the
+/// end of the input or the null byte which ends the file.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Returns
+///
+/// A vector of byte ranges and the VB6 tokens found in them.
+///
+/// # Errors
+///
+/// If the parser encounters an unknown token, it will return an error.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::language::VB6Token;
+/// use vb6parse::parsers::{VB6Stream, vb6_parse_spanned};
+///
+/// let mut input = VB6Stream::new("test.bas", b"Dim x");
+/// let tokens = vb6_parse_spanned(&mut input).unwrap();
+///
+/// assert_eq!(tokens[0], (0..3, VB6Token::DimKeyword("Dim".into())));
+/// assert_eq!(tokens[2], (4..5, VB6Token::VariableName("x".into())));
+/// ```
+pub fn vb6_parse_spanned<'a>(
+    input: &mut VB6Stream<'a>,
+) -> VB6Result<Vec<(Range<usize>, VB6Token<'a>)>> {
+    let mut tokens = Vec::new();
+
+    if !is_english_code(input.stream) {
+        return Err(ErrMode::Cut(VB6ErrorKind::LikelyNonEnglishCharacterSet));
+    }
+
+    loop {
+        let start = input.index;
+
+        let Some(token) = vb6_next_token(input)? else {
             break;
-        }
+        };
 
-        if let Ok(token) = line_ending::<VB6Stream<'a>, VB6ErrorKind>.parse_next(input) {
-            let token = VB6Token::Newline(token);
-            tokens.push(token);
-            continue;
-        }
+        tokens.push((start..input.index, token));
+    }
 
-        if let Ok(token) = line_comment_parse.parse_next(input) {
-            let token = VB6Token::Comment(token);
-            tokens.push(token);
-            continue;
-        }
+    Ok(tokens)
+}
 
-        if let Ok(token) = delimited::<VB6Stream<'a>, _, &BStr, _, VB6ErrorKind, _, _, _>(
-            '\"',
-            take_till(0.., '\"'),
-            '\"',
-        )
-        .take()
+/// Collects the comments from a list of spanned tokens.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens returned by [`vb6_parse_spanned`].
+///
+/// # Returns
+///
+/// The comments in the order they appear in the source.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::language::VB6CommentKind;
+/// use vb6parse::parsers::{vb6::comments, VB6Stream, vb6_parse_spanned};
+///
+/// let mut input = VB6Stream::new("test.bas", b"' Counter\r\nDim x ' the count\r\n");
+/// let tokens = vb6_parse_spanned(&mut input).unwrap();
+/// let comments = comments(&tokens);
+///
+/// assert_eq!(comments[0].text, " Counter");
+/// assert!(comments[0].standalone);
+/// assert_eq!(comments[1].text, " the count");
+/// assert_eq!(comments[1].kind, VB6CommentKind::EndOfLine);
+/// assert!(!comments[1].standalone);
+/// ```
+#[must_use]
+pub fn comments<'a>(tokens: &[(Range<usize>, VB6Token<'a>)]) -> Vec<VB6Comment<'a>> {
+    let mut comments = vec![];
+    let mut standalone = true;
+
+    for (span, token) in tokens {
+        let (text, kind) = match token {
+            VB6Token::Comment(comment) => (&comment[1..], VB6CommentKind::EndOfLine),
+            VB6Token::RemComment(comment) => (&comment[3..], VB6CommentKind::Rem),
+            VB6Token::Newline(_) => {
+                standalone = true;
+                continue;
+            }
+            VB6Token::Whitespace(_) => continue,
+            _ => {
+                standalone = false;
+                continue;
+            }
+        };
+
+        comments.push(VB6Comment {
+            text: text.as_bstr(),
+            span: span.clone(),
+            kind,
+            standalone,
+        });
+    }
+
+    comments
+}
+
+/// Puts the source back together from a list of tokens.
+///
+/// The tokenizer keeps every byte of the input, whitespace and comments
+/// included, so reconstructing the tokens of a file gives back the exact
+/// bytes that were parsed. The result is a byte string rather than a
+/// `String` since VB6 source is usually not UTF-8.
+///
+/// # Arguments
+///
+/// * `tokens` - The tokens returned by [`vb6_parse`].
+///
+/// # Returns
+///
+/// The concatenated text of the tokens.
+///
+/// # Example
+///
+/// ```rust
+/// use vb6parse::parsers::{vb6::reconstruct, VB6Stream, vb6_parse};
+///
+/// let source = b"Dim x As Integer ' counter\r\n";
+/// let mut input = VB6Stream::new("test.bas", source);
+/// let tokens = vb6_parse(&mut input).unwrap();
+///
+/// assert_eq!(reconstruct(&tokens), source.as_slice());
+/// ```
+#[must_use]
+pub fn reconstruct(tokens: &[VB6Token<'_>]) -> BString {
+    let mut source = BString::default();
+
+    for token in tokens {
+        source.extend_from_slice(token.text());
+    }
+
+    source
+}
+
+fn vb6_next_token<'a>(input: &mut VB6Stream<'a>) -> VB6Result<Option<VB6Token<'a>>> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    // The file should end if there is a null byte.
+    if literal::<_, _, VB6ErrorKind>('\0')
         .parse_next(input)
-        {
-            let token = VB6Token::StringLiteral(token);
-            tokens.push(token);
-            continue;
-        }
+        .is_ok()
+    {
+        return Ok(None);
+    }
 
-        if let Ok(token) = vb6_token_parse.parse_next(input) {
-            tokens.push(token);
-            continue;
-        }
+    if let Ok(token) = line_ending::<VB6Stream<'a>, VB6ErrorKind>.parse_next(input) {
+        return Ok(Some(VB6Token::Newline(token)));
+    }
 
-        return Err(ErrMode::Cut(VB6ErrorKind::UnknownToken));
+    if let Ok(token) = line_comment_parse.parse_next(input) {
+        return Ok(Some(VB6Token::Comment(token)));
     }
 
-    Ok(tokens)
+    if let Ok(token) = rem_comment_parse.parse_next(input) {
+        return Ok(Some(VB6Token::RemComment(token)));
+    }
+
+    if let Ok(token) = delimited::<VB6Stream<'a>, _, &BStr, _, VB6ErrorKind, _, _, _>(
+        '\"',
+        take_till(0.., '\"'),
+        '\"',
+    )
+    .take()
+    .parse_next(input)
+    {
+        return Ok(Some(VB6Token::StringLiteral(token)));
+    }
+
+    if let Ok(token) = date_literal_parse.parse_next(input) {
+        return Ok(Some(VB6Token::DateLiteral(token)));
+    }
+
+    if let Ok(token) = vb6_token_parse.parse_next(input) {
+        return Ok(Some(token));
+    }
+
+    Err(ErrMode::Cut(VB6ErrorKind::UnknownToken))
 }
 
 #[must_use]
@@ -323,51 +737,56 @@ pub fn is_english_code(content: &BStr) -> bool {
 fn vb6_keyword_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6Token<'a>> {
     // 'alt' only allows for a limited number of parsers to be passed in.
     // so we need to chain the 'alt' parsers together.
-    alt((
+    // A keyword followed by '$' is a string function such as 'String$'.
+    terminated(
         alt((
-            keyword_parse("Type").map(|token: &BStr| VB6Token::TypeKeyword(token)),
-            keyword_parse("Optional").map(|token: &BStr| VB6Token::OptionalKeyword(token)),
-            keyword_parse("Option").map(|token: &BStr| VB6Token::OptionKeyword(token)),
-            keyword_parse("Explicit").map(|token: &BStr| VB6Token::ExplicitKeyword(token)),
-            keyword_parse("Private").map(|token: &BStr| VB6Token::PrivateKeyword(token)),
-            keyword_parse("Public").map(|token: &BStr| VB6Token::PublicKeyword(token)),
-            keyword_parse("Dim").map(|token: &BStr| VB6Token::DimKeyword(token)),
-            keyword_parse("With").map(|token: &BStr| VB6Token::WithKeyword(token)),
-            keyword_parse("Declare").map(|token: &BStr| VB6Token::DeclareKeyword(token)),
-            keyword_parse("Lib").map(|token: &BStr| VB6Token::LibKeyword(token)),
-            keyword_parse("Const").map(|token: &BStr| VB6Token::ConstKeyword(token)),
-            keyword_parse("As").map(|token: &BStr| VB6Token::AsKeyword(token)),
-            keyword_parse("Enum").map(|token: &BStr| VB6Token::EnumKeyword(token)),
-            keyword_parse("Long").map(|token: &BStr| VB6Token::LongKeyword(token)),
-            keyword_parse("Integer").map(|token: &BStr| VB6Token::IntegerKeyword(token)),
-            keyword_parse("Boolean").map(|token: &BStr| VB6Token::BooleanKeyword(token)),
-            keyword_parse("Byte").map(|token: &BStr| VB6Token::ByteKeyword(token)),
-            keyword_parse("Single").map(|token: &BStr| VB6Token::SingleKeyword(token)),
-            keyword_parse("String").map(|token: &BStr| VB6Token::StringKeyword(token)),
+            alt((
+                keyword_parse("Type").map(|token: &BStr| VB6Token::TypeKeyword(token)),
+                keyword_parse("Optional").map(|token: &BStr| VB6Token::OptionalKeyword(token)),
+                keyword_parse("Option").map(|token: &BStr| VB6Token::OptionKeyword(token)),
+                keyword_parse("Explicit").map(|token: &BStr| VB6Token::ExplicitKeyword(token)),
+                keyword_parse("Implements").map(|token: &BStr| VB6Token::ImplementsKeyword(token)),
+                keyword_parse("Private").map(|token: &BStr| VB6Token::PrivateKeyword(token)),
+                keyword_parse("Public").map(|token: &BStr| VB6Token::PublicKeyword(token)),
+                keyword_parse("Dim").map(|token: &BStr| VB6Token::DimKeyword(token)),
+                keyword_parse("With").map(|token: &BStr| VB6Token::WithKeyword(token)),
+                keyword_parse("Declare").map(|token: &BStr| VB6Token::DeclareKeyword(token)),
+                keyword_parse("Lib").map(|token: &BStr| VB6Token::LibKeyword(token)),
+                keyword_parse("Const").map(|token: &BStr| VB6Token::ConstKeyword(token)),
+                keyword_parse("As").map(|token: &BStr| VB6Token::AsKeyword(token)),
+                keyword_parse("Enum").map(|token: &BStr| VB6Token::EnumKeyword(token)),
+                keyword_parse("Long").map(|token: &BStr| VB6Token::LongKeyword(token)),
+                keyword_parse("Integer").map(|token: &BStr| VB6Token::IntegerKeyword(token)),
+                keyword_parse("Boolean").map(|token: &BStr| VB6Token::BooleanKeyword(token)),
+                keyword_parse("Byte").map(|token: &BStr| VB6Token::ByteKeyword(token)),
+                keyword_parse("Single").map(|token: &BStr| VB6Token::SingleKeyword(token)),
+                keyword_parse("String").map(|token: &BStr| VB6Token::StringKeyword(token)),
+            )),
+            alt((
+                keyword_parse("True").map(|token: &BStr| VB6Token::TrueKeyword(token)),
+                keyword_parse("False").map(|token: &BStr| VB6Token::FalseKeyword(token)),
+                keyword_parse("Function").map(|token: &BStr| VB6Token::FunctionKeyword(token)),
+                keyword_parse("Sub").map(|token: &BStr| VB6Token::SubKeyword(token)),
+                keyword_parse("End").map(|token: &BStr| VB6Token::EndKeyword(token)),
+                keyword_parse("If").map(|token: &BStr| VB6Token::IfKeyword(token)),
+                keyword_parse("Else").map(|token: &BStr| VB6Token::ElseKeyword(token)),
+                keyword_parse("And").map(|token: &BStr| VB6Token::AndKeyword(token)),
+                keyword_parse("Or").map(|token: &BStr| VB6Token::OrKeyword(token)),
+                keyword_parse("Not").map(|token: &BStr| VB6Token::NotKeyword(token)),
+                keyword_parse("Then").map(|token: &BStr| VB6Token::ThenKeyword(token)),
+                keyword_parse("For").map(|token: &BStr| VB6Token::ForKeyword(token)),
+                keyword_parse("To").map(|token: &BStr| VB6Token::ToKeyword(token)),
+                keyword_parse("Step").map(|token: &BStr| VB6Token::StepKeyword(token)),
+                keyword_parse("Next").map(|token: &BStr| VB6Token::NextKeyword(token)),
+                keyword_parse("ReDim").map(|token: &BStr| VB6Token::ReDimKeyword(token)),
+                keyword_parse("ByVal").map(|token: &BStr| VB6Token::ByValKeyword(token)),
+                keyword_parse("ByRef").map(|token: &BStr| VB6Token::ByRefKeyword(token)),
+                keyword_parse("Goto").map(|token: &BStr| VB6Token::GotoKeyword(token)),
+                keyword_parse("Exit").map(|token: &BStr| VB6Token::ExitKeyword(token)),
+            )),
         )),
-        alt((
-            keyword_parse("True").map(|token: &BStr| VB6Token::TrueKeyword(token)),
-            keyword_parse("False").map(|token: &BStr| VB6Token::FalseKeyword(token)),
-            keyword_parse("Function").map(|token: &BStr| VB6Token::FunctionKeyword(token)),
-            keyword_parse("Sub").map(|token: &BStr| VB6Token::SubKeyword(token)),
-            keyword_parse("End").map(|token: &BStr| VB6Token::EndKeyword(token)),
-            keyword_parse("If").map(|token: &BStr| VB6Token::IfKeyword(token)),
-            keyword_parse("Else").map(|token: &BStr| VB6Token::ElseKeyword(token)),
-            keyword_parse("And").map(|token: &BStr| VB6Token::AndKeyword(token)),
-            keyword_parse("Or").map(|token: &BStr| VB6Token::OrKeyword(token)),
-            keyword_parse("Not").map(|token: &BStr| VB6Token::NotKeyword(token)),
-            keyword_parse("Then").map(|token: &BStr| VB6Token::ThenKeyword(token)),
-            keyword_parse("For").map(|token: &BStr| VB6Token::ForKeyword(token)),
-            keyword_parse("To").map(|token: &BStr| VB6Token::ToKeyword(token)),
-            keyword_parse("Step").map(|token: &BStr| VB6Token::StepKeyword(token)),
-            keyword_parse("Next").map(|token: &BStr| VB6Token::NextKeyword(token)),
-            keyword_parse("ReDim").map(|token: &BStr| VB6Token::ReDimKeyword(token)),
-            keyword_parse("ByVal").map(|token: &BStr| VB6Token::ByValKeyword(token)),
-            keyword_parse("ByRef").map(|token: &BStr| VB6Token::ByRefKeyword(token)),
-            keyword_parse("Goto").map(|token: &BStr| VB6Token::GotoKeyword(token)),
-            keyword_parse("Exit").map(|token: &BStr| VB6Token::ExitKeyword(token)),
-        )),
-    ))
+        not('$'),
+    )
     .parse_next(input)
 }
 
@@ -413,10 +832,12 @@ fn vb6_token_parse<'a>(input: &mut VB6Stream<'a>) -> VB6Result<VB6Token<'a>> {
     alt((
         (line_comment_parse).map(|token: &BStr| VB6Token::Comment(token)),
         vb6_keyword_parse,
+        // Numbers come before symbols so that a leading dot, as in '.5', is
+        // read as part of the number rather than as a period.
+        number_parse.map(|token: &BStr| VB6Token::Number(token)),
         vb6_symbol_parse,
         alt((
-            digit1.map(|token: &BStr| VB6Token::Number(token)),
-            variable_name_parse.map(|token: &BStr| VB6Token::VariableName(token)),
+            typed_variable_name_parse.map(|token: &BStr| VB6Token::VariableName(token)),
             space1.map(|token: &BStr| VB6Token::Whitespace(token)),
         )),
     ))
@@ -517,6 +938,126 @@ mod test {
         assert_eq!(variable_name, "variable_name");
     }
 
+    #[test]
+    fn typed_variable_name() {
+        use crate::parsers::VB6Stream;
+        use crate::vb6::{vb6_parse, VB6Token};
+
+        let mut input = VB6Stream::new("", "s$ = \"a\"".as_bytes());
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[0], VB6Token::VariableName("s$".into()));
+        assert_eq!(tokens[1], VB6Token::Whitespace(" ".into()));
+        assert_eq!(tokens[2], VB6Token::EqualityOperator("=".into()));
+        assert_eq!(tokens[3], VB6Token::Whitespace(" ".into()));
+        assert_eq!(tokens[4], VB6Token::StringLiteral("\"a\"".into()));
+
+        let mut input = VB6Stream::new("", "n% = 1".as_bytes());
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[0], VB6Token::VariableName("n%".into()));
+        assert_eq!(tokens[4], VB6Token::Number("1".into()));
+
+        for name in ["l&", "f!", "d#", "c@"] {
+            let source = format!("{name} = 1");
+            let mut input = VB6Stream::new("", source.as_bytes());
+            let tokens = vb6_parse(&mut input).unwrap();
+
+            assert_eq!(tokens.len(), 5, "{name}");
+            assert_eq!(tokens[0], VB6Token::VariableName(name.into()), "{name}");
+        }
+
+        for function in ["Left$", "String$"] {
+            let source = format!("{function}(s, 1)");
+            let mut input = VB6Stream::new("", source.as_bytes());
+            let tokens = vb6_parse(&mut input).unwrap();
+
+            assert_eq!(
+                tokens[0],
+                VB6Token::VariableName(function.into()),
+                "{function}"
+            );
+            assert_eq!(
+                tokens[1],
+                VB6Token::LeftParanthesis("(".into()),
+                "{function}"
+            );
+        }
+    }
+
+    #[test]
+    fn hex_and_octal_numbers() {
+        use crate::parsers::VB6Stream;
+        use crate::vb6::{vb6_parse, VB6Token};
+
+        for number in ["&H00FF&", "&hff", "&H10%", "&O17", "&o17&"] {
+            let source = format!("x = {number}");
+            let mut input = VB6Stream::new("", source.as_bytes());
+            let tokens = vb6_parse(&mut input).unwrap();
+
+            assert_eq!(tokens.len(), 5, "{number}");
+            assert_eq!(tokens[4], VB6Token::Number(number.into()), "{number}");
+        }
+
+        let mut input = VB6Stream::new("", "s = a &Hello".as_bytes());
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(tokens[6], VB6Token::Ampersand("&".into()));
+        assert_eq!(tokens[7], VB6Token::VariableName("Hello".into()));
+    }
+
+    #[test]
+    fn type_character_not_taken_from_following_name() {
+        use crate::parsers::VB6Stream;
+        use crate::vb6::{vb6_parse, VB6Token};
+
+        let mut input = VB6Stream::new("", "x = &H10".as_bytes());
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[0], VB6Token::VariableName("x".into()));
+        assert_eq!(tokens[4], VB6Token::Number("&H10".into()));
+
+        let mut input = VB6Stream::new("", "rs!Field".as_bytes());
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0], VB6Token::VariableName("rs".into()));
+        assert_eq!(tokens[1], VB6Token::ExclamationMark("!".into()));
+        assert_eq!(tokens[2], VB6Token::VariableName("Field".into()));
+    }
+
+    #[test]
+    fn take_until_earliest_terminator() {
+        let mut input = VB6Stream::new("", "{uuid}#2.0#0; stdole2.tlb".as_bytes());
+
+        let (taken, terminator) = take_until_any(&["; ", "#"]).parse_next(&mut input).unwrap();
+
+        assert_eq!(taken, "{uuid}");
+        assert_eq!(terminator, "#");
+        assert_eq!(input.index, 6);
+
+        let mut input = VB6Stream::new("", "Module1; Module1.bas".as_bytes());
+
+        let result = take_until_any(&["]", "|"]).parse_next(&mut input);
+
+        assert!(result.is_err());
+        assert_eq!(input.index, 0);
+    }
+
+    #[test]
+    fn take_while_digits() {
+        let mut input = VB6Stream::new("", "1234 As Integer".as_bytes());
+
+        let digits: VB6Result<&BStr> =
+            take_while(1.., |c: u8| c.is_ascii_digit()).parse_next(&mut input);
+
+        assert_eq!(digits.unwrap(), "1234");
+        assert_eq!(input.column, 5);
+    }
+
     #[test]
     fn vb6_parse() {
         use crate::parsers::VB6Stream;
@@ -535,6 +1076,25 @@ mod test {
         assert_eq!(tokens[6], VB6Token::IntegerKeyword("Integer".into()));
     }
 
+    #[test]
+    fn invalid_character_reported_at_position() {
+        use crate::vb6::vb6_parse;
+        use crate::vb6::VB6Stream;
+
+        let mut input = VB6Stream::new("", "Dim x\r\nx = ~1\r\n".as_bytes());
+
+        let result = vb6_parse(&mut input);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ErrMode::Cut(VB6ErrorKind::UnknownToken)
+        ));
+        assert_eq!(input.index, 11);
+        assert_eq!(input.line_number, 2);
+        assert_eq!(input.column, 5);
+        assert_eq!(input.error(VB6ErrorKind::UnknownToken).source_offset, 11);
+    }
+
     #[test]
     fn non_english_parse() {
         use crate::vb6::vb6_parse;
@@ -584,4 +1144,259 @@ Attribute ProgramIsOnWine.VB_VarUserMemId = 1073741838";
         assert_eq!(key2, "As");
         assert_eq!(key3, "Integer");
     }
+
+    #[test]
+    fn token_groups() {
+        use crate::vb6::vb6_parse;
+
+        let mut input = VB6Stream::new("", b"If x = 5 + \"a\" Then y = True\r\n");
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        let groups = tokens
+            .iter()
+            .map(|token| (token.is_keyword(), token.is_literal(), token.is_operator()))
+            .collect::<Vec<_>>();
+
+        let keyword = (true, false, false);
+        let literal = (false, true, false);
+        let operator = (false, false, true);
+        let other = (false, false, false);
+
+        assert_eq!(
+            groups,
+            vec![
+                keyword, other, other, other, operator, other, literal, other, operator, other,
+                literal, other, keyword, other, other, other, operator, other, literal, other,
+            ]
+        );
+        let mut input = VB6Stream::new("", b"s = \"a\" & b.c: d = 1\r\n");
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        let operators = tokens
+            .iter()
+            .filter(|token| token.is_operator())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            operators,
+            vec![
+                &VB6Token::EqualityOperator("=".into()),
+                &VB6Token::Ampersand("&".into()),
+                &VB6Token::EqualityOperator("=".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn newline_kinds() {
+        use crate::vb6::newline_parse;
+
+        for newline in ["\r\n", "\n", "\r"] {
+            let source = format!("{newline}x");
+            let mut input = VB6Stream::new("", source.as_bytes());
+
+            assert_eq!(newline_parse(&mut input).unwrap(), newline);
+            assert_eq!(input.stream[input.index..], *b"x");
+        }
+
+        let mut input = VB6Stream::new("", b"\n\r\n");
+        assert_eq!(newline_parse(&mut input).unwrap(), "\n");
+
+        let mut input = VB6Stream::new("", b"x\r\n");
+        assert!(newline_parse(&mut input).is_err());
+    }
+
+    #[test]
+    fn rem_comment() {
+        use crate::vb6::vb6_parse;
+
+        let mut input = VB6Stream::new("", b"Rem this is a note\r\nREM\r\nRemCount = 5\r\n");
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                VB6Token::RemComment("Rem this is a note".into()),
+                VB6Token::Newline("\r\n".into()),
+                VB6Token::RemComment("REM".into()),
+                VB6Token::Newline("\r\n".into()),
+                VB6Token::VariableName("RemCount".into()),
+                VB6Token::Whitespace(" ".into()),
+                VB6Token::EqualityOperator("=".into()),
+                VB6Token::Whitespace(" ".into()),
+                VB6Token::Number("5".into()),
+                VB6Token::Newline("\r\n".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn floating_point_numbers() {
+        use crate::vb6::vb6_parse;
+
+        for number in [".5", "1.5E-3", "1.5e+3", "1.5!", "1.5#", "2D10", "10&"] {
+            let mut input = VB6Stream::new("", number.as_bytes());
+            let tokens = vb6_parse(&mut input).unwrap();
+
+            assert_eq!(tokens, vec![VB6Token::Number(number.into())], "{number}");
+        }
+    }
+
+    #[test]
+    fn date_literals() {
+        use crate::vb6::vb6_parse;
+
+        let mut input = VB6Stream::new("", b"d = #1/1/2000 12:30:00 PM#\r\nClose #1\r\n");
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                VB6Token::VariableName("d".into()),
+                VB6Token::Whitespace(" ".into()),
+                VB6Token::EqualityOperator("=".into()),
+                VB6Token::Whitespace(" ".into()),
+                VB6Token::DateLiteral("#1/1/2000 12:30:00 PM#".into()),
+                VB6Token::Newline("\r\n".into()),
+                VB6Token::VariableName("Close".into()),
+                VB6Token::Whitespace(" ".into()),
+                VB6Token::Octothorpe("#".into()),
+                VB6Token::Number("1".into()),
+                VB6Token::Newline("\r\n".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn directive_like_comments() {
+        use crate::vb6::vb6_parse;
+
+        let mut input = VB6Stream::new("", b"'$Include \"foo.bas\"\r\n'#Region Helpers\r\n");
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                VB6Token::Comment("'$Include \"foo.bas\"".into()),
+                VB6Token::Newline("\r\n".into()),
+                VB6Token::Comment("'#Region Helpers".into()),
+                VB6Token::Newline("\r\n".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn leading_and_trailing_comments() {
+        let source = b"' Adds one to the counter.\r\n' Returns the new value.\r\nPublic Function Increment() As Long\r\n    Rem bump\r\n    Increment = 1 ' always one\r\nEnd Function\r\n";
+        let mut input = VB6Stream::new("", source);
+
+        let tokens = vb6_parse_spanned(&mut input).unwrap();
+        let comments = comments(&tokens);
+
+        assert_eq!(
+            comments
+                .iter()
+                .map(|comment| (comment.text, comment.kind, comment.standalone))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    BStr::new(" Adds one to the counter."),
+                    VB6CommentKind::EndOfLine,
+                    true
+                ),
+                (
+                    BStr::new(" Returns the new value."),
+                    VB6CommentKind::EndOfLine,
+                    true
+                ),
+                (BStr::new(" bump"), VB6CommentKind::Rem, true),
+                (BStr::new(" always one"), VB6CommentKind::EndOfLine, false),
+            ]
+        );
+        assert_eq!(&source[comments[3].span.clone()], b"' always one");
+    }
+
+    #[test]
+    fn reconstruct_is_lossless() {
+        // Accented characters have to stay below the non-English threshold,
+        // so those samples are padded out with plain code.
+        let padding = b"x = x + 1\r\n".repeat(40);
+
+        let sources: [Vec<u8>; 5] = [
+            b"Dim x As Integer\r\nx = 1.5E-3 + &H10\r\n".to_vec(),
+            b"Private Sub Main()\n\tIf x Then\t' tabbed\n\t\tx = #1/1/2000#\n\tEnd If\nEnd Sub\n"
+                .to_vec(),
+            [
+                b"Rem header\r\ns = \"caf\xc3\xa9\" & \"na\xefve\"\r\n".as_slice(),
+                &padding,
+            ]
+            .concat(),
+            [
+                b"' r\xe9sum\xe9 in Windows-1252\r\nDebug.Print \"\xc0 bient\xf4t\"  \r\n\r\n"
+                    .as_slice(),
+                &padding,
+            ]
+            .concat(),
+            vec![],
+        ];
+
+        for source in sources {
+            let mut input = VB6Stream::new("", &source);
+            let tokens = super::vb6_parse(&mut input).unwrap();
+
+            assert_eq!(reconstruct(&tokens), source);
+        }
+    }
+
+    #[test]
+    fn spanned_tokens_cover_source() {
+        let source = b"Private Sub Form_Load() ' start\r\n    MsgBox \"Hello\"\r\nEnd Sub\r\n";
+        let mut input = VB6Stream::new("", source);
+
+        let tokens = vb6_parse_spanned(&mut input).unwrap();
+
+        let mut end = 0;
+        for (span, _) in &tokens {
+            assert_eq!(span.start, end);
+            end = span.end;
+        }
+        assert_eq!(end, source.len());
+
+        let string_literal = tokens
+            .iter()
+            .find(|(_, token)| matches!(token, VB6Token::StringLiteral(_)))
+            .unwrap();
+        assert_eq!(
+            *string_literal,
+            (44..51, VB6Token::StringLiteral("\"Hello\"".into()))
+        );
+    }
+
+    #[test]
+    fn canonical_keyword_casing() {
+        use crate::vb6::vb6_parse;
+
+        let mut input = VB6Stream::new(
+            "",
+            b"private sub goto_end()\r\nredim x\r\nGOTO done\r\nend sub\r\n",
+        );
+        let tokens = vb6_parse(&mut input).unwrap();
+
+        let keywords = tokens
+            .iter()
+            .filter_map(|token| token.canonical().map(|canonical| (token, canonical)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            keywords,
+            vec![
+                (&VB6Token::PrivateKeyword("private".into()), "Private"),
+                (&VB6Token::SubKeyword("sub".into()), "Sub"),
+                (&VB6Token::ReDimKeyword("redim".into()), "ReDim"),
+                (&VB6Token::GotoKeyword("GOTO".into()), "GoTo"),
+                (&VB6Token::EndKeyword("end".into()), "End"),
+                (&VB6Token::SubKeyword("sub".into()), "Sub"),
+            ]
+        );
+    }
 }
@@ -22,6 +22,11 @@ pub struct VB6Stream<'a> {
     pub index: usize,
     pub line_number: usize,
     pub column: usize,
+    /// The number of columns between tab stops.
+    ///
+    /// A tab moves the column to the next tab stop. With the default width
+    /// of 1 a tab counts as a single column. Set with `with_tab_width`.
+    tab_width: usize,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -43,6 +48,12 @@ impl Offset<VB6StreamCheckpoint> for VB6Stream<'_> {
 }
 
 impl<'a> VB6Stream<'a> {
+    /// Creates a stream over the given source.
+    ///
+    /// The stream borrows `stream` and never copies it. Every slice handed
+    /// out by the stream, and so every `&'a BStr` in the tokens, controls
+    /// and properties built from it, points back into the original buffer.
+    /// Only the file name is allocated.
     #[must_use]
     pub fn new(file_name: impl Into<String>, stream: &'a [u8]) -> Self {
         Self {
@@ -51,9 +62,33 @@ impl<'a> VB6Stream<'a> {
             index: 0,
             line_number: 1,
             column: 1,
+            tab_width: 1,
         }
     }
 
+    /// Sets the number of columns between tab stops used when tracking the
+    /// column.
+    ///
+    /// A width of 0 is treated as 1, so a tab always moves at least one
+    /// column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use winnow::stream::Stream;
+    /// use vb6parse::parsers::VB6Stream;
+    ///
+    /// let mut input = VB6Stream::new("test.bas", b"\tDim x").with_tab_width(4);
+    /// input.next_slice(1);
+    ///
+    /// assert_eq!(input.column, 5);
+    /// ```
+    #[must_use]
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.stream.len() == self.index
@@ -63,6 +98,107 @@ impl<'a> VB6Stream<'a> {
     pub fn error(&self, kind: VB6ErrorKind) -> VB6Error {
         VB6Error::new(self, kind)
     }
+
+    /// Returns the number of lines in the source.
+    ///
+    /// This is the line the end of the source is on, so a trailing newline
+    /// does not start a new line and an empty source has a single line.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.line_number_at(self.stream.len())
+    }
+
+    /// Returns the text of the given 1-based line without its line ending.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Stream;
+    ///
+    /// let input = VB6Stream::new("test.bas", b"Option Explicit\r\nDim x\r\n");
+    ///
+    /// assert_eq!(input.line_text(2), Some(b"Dim x".into()));
+    /// assert_eq!(input.line_text(3), None);
+    /// ```
+    #[must_use]
+    pub fn line_text(&self, line: usize) -> Option<&'a bstr::BStr> {
+        let stream: &'a [u8] = self.stream;
+
+        stream
+            .lines()
+            .nth(line.checked_sub(1)?)
+            .map(ByteSlice::as_bstr)
+    }
+
+    /// Returns the 1-based line number the stream is currently on.
+    ///
+    /// This agrees with `line_number_at` for the current offset. Unlike the
+    /// `line_number` field, it stays on the last line once the newline which
+    /// ends the source has been consumed.
+    #[must_use]
+    pub fn current_line(&self) -> usize {
+        if self.is_trailing_newline_end(self.index) {
+            return self.line_number - 1;
+        }
+
+        self.line_number
+    }
+
+    /// Returns the 1-based line number of the given byte offset.
+    ///
+    /// A line is started by each newline before the offset, except for a
+    /// newline which ends the source, so the end of the source is on line
+    /// `line_count()`. An offset past the end of the source is treated as
+    /// the end of the source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vb6parse::parsers::VB6Stream;
+    ///
+    /// let input = VB6Stream::new("test.bas", b"Option Explicit\r\nDim x\r\n");
+    ///
+    /// assert_eq!(input.line_number_at(0), 1);
+    /// assert_eq!(input.line_number_at(17), 2);
+    /// assert_eq!(input.line_number_at(24), input.line_count());
+    /// ```
+    #[must_use]
+    pub fn line_number_at(&self, offset: usize) -> usize {
+        let offset = offset.min(self.stream.len());
+        let newlines = self.stream[..offset].find_iter("\n").count();
+
+        if self.is_trailing_newline_end(offset) {
+            return newlines;
+        }
+
+        newlines + 1
+    }
+
+    /// Returns true if the offset is the end of a source which ends with a
+    /// newline. That newline ends the last line rather than starting another.
+    fn is_trailing_newline_end(&self, offset: usize) -> bool {
+        offset == self.stream.len() && self.stream.ends_with(b"\n")
+    }
+
+    fn advance_position(&mut self, token: u8) {
+        match token {
+            b'\n' => {
+                // on newline we need to reset the column and increment
+                // the line number
+                self.line_number += 1;
+                self.column = 1;
+            }
+            b'\t' => {
+                // a defaulted stream has a tab width of 0.
+                let tab_width = self.tab_width.max(1);
+
+                self.column = ((self.column - 1) / tab_width + 1) * tab_width + 1;
+            }
+            _ => {
+                self.column += 1;
+            }
+        }
+    }
 }
 
 impl<'a> FindSlice<&str> for VB6Stream<'a> {
@@ -73,6 +209,23 @@ impl<'a> FindSlice<&str> for VB6Stream<'a> {
     }
 }
 
+impl FindSlice<Caseless<&str>> for VB6Stream<'_> {
+    fn find_slice(&self, needle: Caseless<&str>) -> Option<std::ops::Range<usize>> {
+        let needle = needle.0.as_bytes();
+
+        if needle.is_empty() {
+            return Some(0..0);
+        }
+
+        // Only ASCII letters are folded, so the bytes of a multi-byte
+        // character are always compared exactly.
+        self.stream[self.index..]
+            .windows(needle.len())
+            .position(|window| window.eq_ignore_ascii_case(needle))
+            .map(|start| start..start + needle.len())
+    }
+}
+
 impl<'a> FindSlice<(&str, &str)> for VB6Stream<'a> {
     fn find_slice(&self, needle: (&str, &str)) -> Option<std::ops::Range<usize>> {
         for needle in &[needle.0, needle.1] {
@@ -328,14 +481,7 @@ impl<'a> Stream for VB6Stream<'a> {
         let (token, _) = self.stream[self.index..].split_first()?;
         self.index += 1;
 
-        if *token == b'\n' {
-            // if we have a newline then we need to reset the
-            // column and line number.
-            self.line_number += 1;
-            self.column = 1;
-        } else {
-            self.column += 1;
-        }
+        self.advance_position(*token);
 
         Some(*token)
     }
@@ -364,14 +510,7 @@ impl<'a> Stream for VB6Stream<'a> {
         self.index += offset;
 
         for token in slice.iter() {
-            if *token == b'\n' {
-                // on newline we need to reset the column and increment
-                // the line number
-                self.line_number += 1;
-                self.column = 1;
-            } else {
-                self.column += 1;
-            }
+            self.advance_position(*token);
         }
 
         slice
@@ -464,6 +603,100 @@ mod tests {
         assert_eq!(stream.compare("Hello, World! "), CompareResult::Incomplete);
     }
 
+    #[test]
+    fn line_text() {
+        let stream = VB6Stream::new("", b"Option Explicit\r\nDim x\nx = 1\r\n");
+
+        assert_eq!(stream.line_count(), 3);
+        assert_eq!(stream.line_text(0), None);
+        assert_eq!(
+            stream.line_text(1),
+            Some("Option Explicit".as_bytes().as_bstr())
+        );
+        assert_eq!(stream.line_text(2), Some("Dim x".as_bytes().as_bstr()));
+        assert_eq!(stream.line_text(3), Some("x = 1".as_bytes().as_bstr()));
+        assert_eq!(stream.line_text(4), None);
+    }
+
+    #[test]
+    fn current_line() {
+        let mut stream = VB6Stream::new("", b"Option Explicit\r\nDim x\nx = 1\r\n");
+
+        assert_eq!(stream.current_line(), 1);
+
+        stream.next_slice(23);
+
+        assert_eq!(stream.current_line(), 3);
+        assert_eq!(stream.current_line(), stream.line_number_at(stream.index));
+        assert_eq!(stream.line_number_at(16), 1);
+        assert_eq!(stream.line_number_at(17), 2);
+        assert_eq!(stream.line_number_at(1000), 3);
+    }
+
+    #[test]
+    fn current_line_matches_line_number_at() {
+        let source = b"Option Explicit\r\nDim x\n\r\nx = 1\r\n";
+        let mut stream = VB6Stream::new("", source);
+
+        for offset in 0..=source.len() {
+            assert_eq!(stream.index, offset);
+            assert_eq!(stream.current_line(), stream.line_number_at(offset));
+
+            stream.next_token();
+        }
+
+        assert!(stream.is_empty());
+        assert_eq!(stream.current_line(), 4);
+        assert_eq!(stream.line_count(), 4);
+    }
+
+    #[test]
+    fn line_number_at_end_matches_line_count() {
+        let sources: [&[u8]; 5] = [
+            b"",
+            b"Option Explicit",
+            b"Option Explicit\r\n",
+            b"Option Explicit\r\nDim x\n",
+            b"Option Explicit\r\n\r\n",
+        ];
+
+        for source in sources {
+            let stream = VB6Stream::new("", source);
+
+            assert_eq!(
+                stream.line_number_at(source.len()),
+                stream.line_count(),
+                "{:?}",
+                source.as_bstr()
+            );
+        }
+    }
+
+    #[test]
+    fn take_until_caseless() {
+        use winnow::{ascii::Caseless, token::take_until, Parser};
+
+        let mut stream = VB6Stream::new("", "Caption = \"Ünïcode\"\r\nend\r\n".as_bytes());
+
+        let taken = take_until::<_, _, winnow::error::ContextError>(0.., Caseless("END"))
+            .parse_next(&mut stream)
+            .unwrap();
+
+        assert_eq!(taken, "Caption = \"Ünïcode\"\r\n".as_bytes().as_bstr());
+        assert_eq!(stream.compare(Caseless("END")), CompareResult::Ok(3));
+        assert_eq!(stream.line_number, 2);
+
+        let mut stream = VB6Stream::new("", "ÜNÏCODE".as_bytes());
+
+        assert_eq!(stream.find_slice(Caseless("ünïcode")), None);
+        assert_eq!(stream.find_slice(Caseless("code")), Some(5..9));
+        assert!(
+            take_until::<_, _, winnow::error::ContextError>(0.., Caseless("end"))
+                .parse_next(&mut stream)
+                .is_err()
+        );
+    }
+
     #[test]
     fn eof_offset() {
         let wstream = b"Hello, World!".as_slice();
@@ -793,4 +1026,29 @@ mod tests {
         assert_eq!(wstream.next_token(), None);
         assert_eq!(stream.next_token(), None);
     }
+
+    #[test]
+    fn tab_width_column() {
+        let mut stream = VB6Stream::new("", b"\tx = 1");
+        stream.next_slice(2);
+
+        assert_eq!(stream.column, 3);
+
+        let mut stream = VB6Stream::new("", b"\tx = 1").with_tab_width(4);
+        stream.next_slice(2);
+
+        assert_eq!(stream.column, 6);
+
+        let mut stream = VB6Stream::new("", b"ab\tx").with_tab_width(4);
+        stream.next_token();
+        stream.next_token();
+        stream.next_token();
+
+        assert_eq!(stream.column, 5);
+
+        let mut stream = VB6Stream::new("", b"\tx").with_tab_width(0);
+        stream.next_token();
+
+        assert_eq!(stream.column, 2);
+    }
 }